@@ -1,19 +1,31 @@
-use crate::{numeric, ToStr};
+use crate::{numeric, Error, ToStr};
 
 use core::{fmt, mem};
 
 ///Static buffer to hold written text.
 ///
 ///Implementation of `ToStr` must write it from the end.
+///
+///Capacity is limited to `u16::MAX` bytes, which is checked at compile time.
+///
+///`Display` honours width, fill, alignment and precision of `Formatter`, same as `str`.
+///
+///```compile_fail
+///let buffer = to_str::Buffer::<65536>::new();
+///```
 pub struct Buffer<const N: usize> {
     inner: [core::mem::MaybeUninit<u8>; N],
-    offset: u8,
+    offset: u16,
 }
 
 impl<const N: usize> Buffer<N> {
     #[inline]
     ///Creates new instance
     pub const fn new() -> Self {
+        const {
+            assert!(N <= u16::MAX as usize, "Buffer capacity is limited to u16::MAX bytes");
+        }
+
         Self {
             #[cfg(debug_assertions)]
             inner: [mem::MaybeUninit::zeroed(); N],
@@ -29,12 +41,6 @@ impl<const N: usize> Buffer<N> {
         self.inner.as_ptr() as _
     }
 
-    #[inline]
-    ///Returns pointer  to the beginning of underlying buffer
-    const fn as_mut_ptr(&mut self) -> *mut u8 {
-        self.inner.as_mut_ptr() as _
-    }
-
     #[inline]
     ///Returns buffer overall capacity.
     pub const fn capacity() -> usize {
@@ -63,7 +69,7 @@ impl<const N: usize> Buffer<N> {
     ///Buffer remembers the write, therefore `as_str()` will return the same text as last
     ///`write`
     pub fn write<T: ToStr>(&mut self, val: T) -> &str {
-        self.offset = (Self::capacity() - self.format(val).len()) as u8;
+        self.offset = (Self::capacity() - self.format(val).len()) as u16;
         self.as_str()
     }
 
@@ -72,15 +78,30 @@ impl<const N: usize> Buffer<N> {
     ///
     ///Buffer remains unaware of modifications
     pub fn format<T: ToStr>(&mut self, val: T) -> &str {
-        //Yes, because we cannot assert statically in generics, we must go through these hacks
-        //We can add this assertion once panic will be allowed inside const fn
-        debug_assert!(Self::capacity() <= u8::max_value() as usize);
         debug_assert!(T::TEXT_SIZE <= Self::capacity());
 
-        val.to_str(unsafe {
-            &mut *core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), Self::capacity())
+        val.to_str_uninit(&mut self.inner)
+    }
+    #[inline]
+    ///Formats value into buffer, returning text or error if capacity is not sufficient.
+    ///
+    ///Buffer remembers the write, therefore `as_str()` will return the same text as last
+    ///`write`
+    pub fn try_write<T: ToStr>(&mut self, val: T) -> Result<&str, Error> {
+        self.offset = (Self::capacity() - self.try_format(val)?.len()) as u16;
+        Ok(self.as_str())
+    }
+
+    #[inline]
+    ///Formats value into buffer, returning text or error if capacity is not sufficient.
+    ///
+    ///Buffer remains unaware of modifications
+    pub fn try_format<T: ToStr>(&mut self, val: T) -> Result<&str, Error> {
+        val.try_to_str(unsafe {
+            &mut *(&mut self.inner[..] as *mut [mem::MaybeUninit<u8>] as *mut [u8])
         })
     }
+
     #[inline(always)]
     ///Creates new instance with formatted value.
     pub fn fmt<T: crate::ToStr>(val: T) -> Self {
@@ -105,7 +126,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u8 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::u8(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::u8(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -123,7 +144,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u16 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::u16(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::u16(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -141,7 +162,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u32 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::u32(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::u32(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -159,7 +180,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u64 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::u64(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::u64(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -177,7 +198,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <usize as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::usize(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::usize(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -195,7 +216,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u128 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::unsigned::u128(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::unsigned::u128(val, &mut this.inner).len()) as u16;
         this
     }
 }
@@ -215,7 +236,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u8 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::i8(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::i8(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -233,7 +254,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <u16 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::i16(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::i16(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -251,7 +272,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <i32 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::i32(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::i32(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -269,7 +290,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <i64 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::i64(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::i64(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -287,7 +308,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <isize as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::isize(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::isize(val, &mut this.inner).len()) as u16;
         this
     }
 
@@ -305,7 +326,7 @@ impl<const N: usize> Buffer<N> {
         assert!(Self::capacity() >= <i128 as ToStr>::TEXT_SIZE, "Capacity should be sufficient");
 
         let mut this = Self::new();
-        this.offset = (Self::capacity() - numeric::signed::i128(val, &mut this.inner).len()) as u8;
+        this.offset = (Self::capacity() - numeric::signed::i128(val, &mut this.inner).len()) as u16;
         this
     }
 }
@@ -320,7 +341,7 @@ impl<const N: usize> AsRef<str> for Buffer<N> {
 impl<const N: usize> fmt::Display for Buffer<N> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.write_str(self.as_str())
+        fmt.pad(self.as_str())
     }
 }
 
@@ -0,0 +1,298 @@
+use crate::{buffer_tail, Error, ToStr};
+
+use core::{fmt, str};
+use core::mem::MaybeUninit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Policy to apply when captured text exceeds capacity
+pub struct Truncation {
+    ///Specifies whether text is cut only on character boundary.
+    ///
+    ///Otherwise text is cut at exact capacity, and incomplete character is only visible via `as_bytes()`
+    pub char_boundary: bool,
+    ///Text to put at the end of truncated text, replacing its tail.
+    ///
+    ///Ignored if it exceeds capacity.
+    pub ellipsis: &'static str,
+}
+
+impl Truncation {
+    ///Cuts text on character boundary without ellipsis
+    pub const CHAR: Self = Self {
+        char_boundary: true,
+        ellipsis: "",
+    };
+    ///Cuts text at exact capacity without ellipsis
+    pub const BYTE: Self = Self {
+        char_boundary: false,
+        ellipsis: "",
+    };
+
+    #[inline(always)]
+    ///Sets `ellipsis` to put at the end of truncated text
+    pub const fn with_ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+}
+
+impl Default for Truncation {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::CHAR
+    }
+}
+
+#[inline(always)]
+const fn is_char_boundary(byte: u8) -> bool {
+    //Continuation bytes are `0b10xx_xxxx`
+    (byte as i8) >= -0x40
+}
+
+///Fixed size buffer to capture output of `Display` or `Debug`
+///
+///Text is written from the beginning of buffer, and once capacity is exceeded it is truncated
+///according to `Truncation` policy, failing all further writes with `fmt::Error`.
+///
+///```
+///use to_str::{FmtBuffer, Truncation};
+///use core::fmt::Write;
+///
+///let text = FmtBuffer::<8>::capture(format_args!("{:?}", [1, 2]));
+///assert_eq!(text.as_str(), "[1, 2]");
+///assert!(!text.is_truncated());
+///
+///let mut text = FmtBuffer::<8>::with_policy(Truncation::CHAR.with_ellipsis("..."));
+///assert!(write!(text, "{}", "Hello world").is_err());
+///assert_eq!(text.as_str(), "Hello...");
+///assert!(text.is_truncated());
+///```
+pub struct FmtBuffer<const N: usize> {
+    inner: [MaybeUninit<u8>; N],
+    len: usize,
+    truncated: bool,
+    policy: Truncation,
+}
+
+impl<const N: usize> FmtBuffer<N> {
+    #[inline]
+    ///Creates new instance with default policy, cutting text on character boundary.
+    pub const fn new() -> Self {
+        Self::with_policy(Truncation::CHAR)
+    }
+
+    #[inline]
+    ///Creates new instance with specified truncation `policy`
+    pub const fn with_policy(policy: Truncation) -> Self {
+        Self {
+            inner: [MaybeUninit::uninit(); N],
+            len: 0,
+            truncated: false,
+            policy,
+        }
+    }
+
+    #[inline]
+    ///Creates new instance with captured `value`
+    pub fn capture<T: fmt::Display>(value: T) -> Self {
+        let mut this = Self::new();
+        let _ = fmt::Write::write_fmt(&mut this, format_args!("{value}"));
+        this
+    }
+
+    #[inline(always)]
+    ///Returns buffer overall capacity.
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    #[inline(always)]
+    ///Returns number of captured bytes
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether nothing has been captured
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Returns whether text has been truncated
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    #[inline(always)]
+    ///Discards captured text
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    #[inline(always)]
+    ///Access captured bytes, which may end with incomplete character when cutting on bytes.
+    pub const fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(self.inner.as_ptr() as *const u8, self.len)
+        }
+    }
+
+    #[inline]
+    ///Access captured text, omitting trailing incomplete character, if any.
+    pub const fn as_str(&self) -> &str {
+        let bytes = self.as_bytes();
+        match str::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(error) => unsafe {
+                str::from_utf8_unchecked(core::slice::from_raw_parts(bytes.as_ptr(), error.valid_up_to()))
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn append(&mut self, bytes: &[u8]) {
+        unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), (self.inner.as_mut_ptr() as *mut u8).add(self.len), bytes.len());
+        }
+        self.len += bytes.len();
+    }
+
+    fn truncate(&mut self, text: &str) {
+        let mut cut = N - self.len;
+        if self.policy.char_boundary {
+            while !text.is_char_boundary(cut) {
+                cut -= 1;
+            }
+        }
+        self.append(&text.as_bytes()[..cut]);
+        self.truncated = true;
+
+        let ellipsis = self.policy.ellipsis;
+        if ellipsis.len() <= N {
+            //Ellipsis is always put on character boundary
+            let mut end = core::cmp::min(self.len, N - ellipsis.len());
+            while end > 0 && end < self.len && !is_char_boundary(self.as_bytes()[end]) {
+                end -= 1;
+            }
+            self.len = end;
+            self.append(ellipsis.as_bytes());
+        }
+    }
+}
+
+impl<const N: usize> fmt::Write for FmtBuffer<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        if self.truncated {
+            Err(fmt::Error)
+        } else if text.len() <= N - self.len {
+            self.append(text.as_bytes());
+            Ok(())
+        } else {
+            self.truncate(text);
+            Err(fmt::Error)
+        }
+    }
+}
+
+impl<const N: usize> Default for FmtBuffer<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FmtBuffer<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for FmtBuffer<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FmtBuffer<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Wrapper to format any `Display` value as `ToStr`, limiting text to `N` bytes.
+///
+///Text exceeding `N` bytes is truncated according to `Truncation` policy.
+///
+///```
+///use to_str::{Bounded, Buffer, Truncation};
+///
+///assert_eq!(Buffer::<8>::fmt(Bounded::<_, 8>::new(1.5f32)).as_str(), "1.5");
+///assert_eq!(Buffer::<8>::fmt(Bounded::<_, 8>::new("Hello world")).as_str(), "Hello wo");
+///
+///let bounded = Bounded::<_, 8>::new("Hello world").with_policy(Truncation::CHAR.with_ellipsis("~"));
+///assert_eq!(Buffer::<8>::fmt(bounded).as_str(), "Hello w~");
+///```
+pub struct Bounded<T, const N: usize> {
+    value: T,
+    policy: Truncation,
+}
+
+impl<T, const N: usize> Bounded<T, N> {
+    #[inline(always)]
+    ///Creates new instance with default policy, cutting text on character boundary.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            policy: Truncation::CHAR,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets truncation `policy`
+    pub const fn with_policy(mut self, policy: Truncation) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<T: fmt::Display, const N: usize> Bounded<T, N> {
+    #[inline]
+    fn capture(&self) -> FmtBuffer<N> {
+        let mut captured = FmtBuffer::<N>::with_policy(self.policy);
+        let _ = fmt::Write::write_fmt(&mut captured, format_args!("{}", self.value));
+        captured
+    }
+}
+
+unsafe impl<T: fmt::Display, const N: usize> ToStr for Bounded<T, N> {
+    const TEXT_SIZE: usize = N;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        match self.try_to_str(buffer) {
+            Ok(text) => text,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        self.capture().as_str().len()
+    }
+
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let captured = self.capture();
+        let text = captured.as_str();
+        let buffer = buffer_tail(buffer, text.len())?;
+        buffer.copy_from_slice(text.as_bytes());
+        Ok(unsafe {
+            str::from_utf8_unchecked(buffer)
+        })
+    }
+}
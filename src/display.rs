@@ -0,0 +1,71 @@
+use crate::{ToStr, SCRATCH_SIZE};
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+//Capacity of the biggest stack buffer.
+const MAX_SIZE: usize = 1024;
+
+//Pads text according to formatter's flags.
+//
+//Integer text is padded via `pad_integral`, therefore honouring `+` and `0` flags.
+fn pad(text: &str, is_integer: bool, fmt: &mut fmt::Formatter) -> fmt::Result {
+    if fmt.width().is_none() && fmt.precision().is_none() && !fmt.sign_plus() {
+        fmt.write_str(text)
+    } else if is_integer {
+        match text.strip_prefix('-') {
+            Some(digits) => fmt.pad_integral(false, "", digits),
+            None => fmt.pad_integral(true, "", text),
+        }
+    } else {
+        fmt.pad(text)
+    }
+}
+
+#[inline(never)]
+//Formats `value` into stack buffer of `N` bytes.
+fn pad_with<T: ToStr, const N: usize>(value: &T, fmt: &mut fmt::Formatter) -> fmt::Result {
+    let mut scratch = [MaybeUninit::uninit(); N];
+    let scratch = unsafe {
+        &mut *(&mut scratch[..] as *mut [MaybeUninit<u8>] as *mut [u8])
+    };
+
+    match value.try_to_str(scratch) {
+        Ok(text) => pad(text, value.is_integer(), fmt),
+        Err(_) => Err(fmt::Error),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+///Wrapper to format `ToStr` value via `Display`
+///
+///Value is written into stack buffer of `256` bytes, after which `Formatter` flags are applied.
+///If `TEXT_SIZE` exceeds it, buffer of `1024` bytes is used when `text_len()` fits.
+///Text exceeding `1024` bytes fails with `fmt::Error`, use `Buffer` or `ToStr::try_to_str()` for it instead.
+///
+///Integer text, as reported by `ToStr::is_integer()`, honours width, fill, alignment, `+` and `0` flags,
+///while any other text honours width, fill, alignment and precision.
+///
+///```
+///use to_str::Displayed;
+///
+///assert_eq!(format!("{:>6}", Displayed(-15i32)), "   -15");
+///assert_eq!(format!("{:+06}", Displayed(15u8)), "+00015");
+///assert_eq!(format!("{:*^7}", Displayed(0usize)), "***0***");
+///```
+pub struct Displayed<T>(pub T);
+
+impl<T: ToStr> fmt::Display for Displayed<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if T::TEXT_SIZE <= SCRATCH_SIZE {
+            return pad_with::<T, SCRATCH_SIZE>(&self.0, fmt);
+        }
+
+        match self.0.text_len() {
+            0..=SCRATCH_SIZE => pad_with::<T, SCRATCH_SIZE>(&self.0, fmt),
+            0..=MAX_SIZE => pad_with::<T, MAX_SIZE>(&self.0, fmt),
+            _ => Err(fmt::Error),
+        }
+    }
+}
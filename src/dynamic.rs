@@ -0,0 +1,162 @@
+use crate::{Error, ToStr};
+
+use core::mem::MaybeUninit;
+
+///Object safe counterpart of `ToStr`
+///
+///Implemented for every `ToStr`, allowing to work with heterogeneous values as `&dyn DynToStr`.
+///
+///```
+///use to_str::DynToStr;
+///
+///let values: [&dyn DynToStr; 3] = [&1u8, &-1i64, &u128::MAX];
+///let mut buffer = [0u8; 64];
+///
+///assert_eq!(values[0].text_size(), 3);
+///assert_eq!(values[1].dyn_to_str(&mut buffer), "-1");
+///assert!(values[2].dyn_try_to_str(&mut buffer[..4]).is_err());
+///```
+pub trait DynToStr {
+    ///Returns `TEXT_SIZE` of the underlying type
+    fn text_size(&self) -> usize;
+
+    ///Returns length of textual representation in bytes
+    ///
+    ///Refer to `ToStr::text_len()`
+    fn dyn_text_len(&self) -> usize;
+
+    ///Writes textual representation to the buffer
+    ///
+    ///Refer to `ToStr::to_str()`
+    fn dyn_to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str;
+
+    ///Writes textual representation to the uninitialized buffer
+    ///
+    ///Refer to `ToStr::to_str_uninit()`
+    fn dyn_to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str;
+
+    ///Writes textual representation to the buffer, if it is sufficient.
+    ///
+    ///Refer to `ToStr::try_to_str()`
+    fn dyn_try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error>;
+}
+
+impl<T: ToStr> DynToStr for T {
+    #[inline(always)]
+    fn text_size(&self) -> usize {
+        T::TEXT_SIZE
+    }
+
+    #[inline(always)]
+    fn dyn_text_len(&self) -> usize {
+        self.text_len()
+    }
+
+    #[inline(always)]
+    fn dyn_to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        self.to_str(buffer)
+    }
+
+    #[inline(always)]
+    fn dyn_to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+        self.to_str_uninit(buffer)
+    }
+
+    #[inline(always)]
+    fn dyn_try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        self.try_to_str(buffer)
+    }
+}
+
+macro_rules! impl_any_num {
+    ($($variant:ident($t:ident)),* $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        ///Number of any primitive type, known at runtime.
+        pub enum AnyNum {
+            $(
+                #[doc = concat!("`", stringify!($t), "` value")]
+                $variant($t),
+            )*
+        }
+
+        $(
+            impl From<$t> for AnyNum {
+                #[inline(always)]
+                fn from(value: $t) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+
+        unsafe impl ToStr for AnyNum {
+            const TEXT_SIZE: usize = {
+                let mut size = 0;
+                $(
+                    if <$t as ToStr>::TEXT_SIZE > size {
+                        size = <$t as ToStr>::TEXT_SIZE;
+                    }
+                )*
+                size
+            };
+
+            #[inline]
+            fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                match self {
+                    $(Self::$variant(value) => value.to_str(buffer),)*
+                }
+            }
+
+            #[inline]
+            fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+                match self {
+                    $(Self::$variant(value) => value.to_str_uninit(buffer),)*
+                }
+            }
+
+            #[inline]
+            fn text_len(&self) -> usize {
+                match self {
+                    $(Self::$variant(value) => value.text_len(),)*
+                }
+            }
+
+            #[inline]
+            fn is_integer(&self) -> bool {
+                match self {
+                    $(Self::$variant(value) => value.is_integer(),)*
+                }
+            }
+
+            #[inline]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                match self {
+                    $(Self::$variant(value) => value.try_to_str(buffer),)*
+                }
+            }
+
+            #[inline]
+            fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+                match self {
+                    $(Self::$variant(value) => value.to_str_front(buffer),)*
+                }
+            }
+        }
+    };
+}
+
+impl_any_num!(
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    F32(f32),
+    F64(f64),
+);
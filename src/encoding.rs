@@ -0,0 +1,808 @@
+//!Binary-to-text encodings of byte arrays
+//!
+//!```
+//!use to_str::{Buffer, Case};
+//!use to_str::encoding::{HexBytes, Separator};
+//!
+//!let key = HexBytes::new([0xde, 0xad, 0xbe, 0xef]);
+//!assert_eq!(Buffer::<11>::fmt(key).as_str(), "deadbeef");
+//!assert_eq!(Buffer::<11>::fmt(key.with_separator(Separator::Colon).with_case(Case::Upper)).as_str(), "DE:AD:BE:EF");
+//!assert_eq!(Buffer::<11>::fmt(key.reversed()).as_str(), "efbeadde");
+//!```
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::encoding::{Base64, Base64Alphabet};
+//!
+//!let token = Base64::new(*b"hi?>");
+//!assert_eq!(Buffer::<8>::fmt(token).as_str(), "aGk/Pg==");
+//!assert_eq!(Buffer::<8>::fmt(token.with_alphabet(Base64Alphabet::UrlSafe).unpadded()).as_str(), "aGk_Pg");
+//!```
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::encoding::{Base32, Base58, Z85};
+//!
+//!assert_eq!(Buffer::<16>::fmt(Base32::new(*b"foobar")).as_str(), "MZXW6YTBOI======");
+//!assert_eq!(Buffer::<17>::fmt(Base58::new(*b"Hello World!")).as_str(), "2NEpo7TZRRrLZSi2U");
+//!assert_eq!(Buffer::<10>::fmt(Z85::new([0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b])).as_str(), "HelloWorld");
+//!```
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::encoding::{EncodeSet, PercentEncoded};
+//!
+//!assert_eq!(Buffer::<21>::fmt(PercentEncoded::new(*b"a b&c=d")).as_str(), "a%20b%26c%3Dd");
+//!assert_eq!(Buffer::<21>::fmt(PercentEncoded::new(*b"a b&c=d").with_set(EncodeSet::Form)).as_str(), "a+b%26c%3Dd");
+//!```
+
+use crate::{buffer_tail, Buffer, Case, Error, FmtBuffer, ToStr};
+
+//Implements `ToStr` for wrapper with `len()` and `write()` methods, `[u8; N]` by default
+macro_rules! impl_to_str {
+    ($name:ident: $size:expr) => {
+        impl_to_str!($name<[u8; N]>: $size);
+    };
+    ($t:ty: $size:expr) => {
+        unsafe impl<const N: usize> ToStr for $t {
+            const TEXT_SIZE: usize = $size;
+
+            #[inline]
+            fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+                let start = buffer.len() - self.len();
+                let buffer = &mut buffer[start..];
+                self.write(buffer);
+                unsafe {
+                    core::str::from_utf8_unchecked(buffer)
+                }
+            }
+
+            #[inline(always)]
+            fn text_len(&self) -> usize {
+                self.len()
+            }
+
+            #[inline]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                let buffer = buffer_tail(buffer, self.len())?;
+                self.write(buffer);
+                Ok(unsafe {
+                    core::str::from_utf8_unchecked(buffer)
+                })
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Separator between encoded bytes
+pub enum Separator {
+    ///Colon `:`
+    Colon,
+    ///Hyphen `-`
+    Hyphen,
+    ///Space ` `
+    Space,
+}
+
+impl Separator {
+    #[inline(always)]
+    const fn as_byte(self) -> u8 {
+        match self {
+            Separator::Colon => b':',
+            Separator::Hyphen => b'-',
+            Separator::Space => b' ',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Hex encoding of bytes
+///
+///`ToStr` is implemented for `[u8; N]`.
+pub struct HexBytes<T> {
+    ///Bytes to encode
+    pub bytes: T,
+    ///Case of hex digits
+    pub case: Case,
+    ///Optional separator between bytes
+    pub separator: Option<Separator>,
+    ///Whether to write bytes in reverse order, e.g. to display little-endian value
+    pub reversed: bool,
+}
+
+impl<T> HexBytes<T> {
+    #[inline(always)]
+    ///Creates new instance, writing bytes in order using lowercase digits without separators
+    pub const fn new(bytes: T) -> Self {
+        Self {
+            bytes,
+            case: Case::Lower,
+            separator: None,
+            reversed: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets case of hex digits
+    pub const fn with_case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets separator between bytes
+    pub const fn with_separator(mut self, separator: Separator) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    #[inline(always)]
+    ///Sets bytes to be written in reverse order
+    pub const fn reversed(mut self) -> Self {
+        self.reversed = true;
+        self
+    }
+}
+
+impl<const N: usize> HexBytes<[u8; N]> {
+    #[inline]
+    const fn len(&self) -> usize {
+        match self.separator {
+            Some(_) => (3 * N).saturating_sub(1),
+            None => 2 * N,
+        }
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let pairs = self.case.hex_pairs();
+        let step = match self.separator {
+            Some(_) => 3,
+            None => 2,
+        };
+
+        for (idx, chunk) in buffer.chunks_mut(step).enumerate() {
+            let byte = match self.reversed {
+                true => self.bytes[N - 1 - idx],
+                false => self.bytes[idx],
+            } as usize;
+            chunk[0] = pairs[byte * 2];
+            chunk[1] = pairs[byte * 2 + 1];
+            if let (Some(separator), Some(last)) = (self.separator, chunk.get_mut(2)) {
+                *last = separator.as_byte();
+            }
+        }
+    }
+}
+
+unsafe impl<const N: usize> ToStr for HexBytes<[u8; N]> {
+    const TEXT_SIZE: usize = (3 * N).saturating_sub(1);
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.len();
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len())?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///Alphabet of Base64 encoding
+pub enum Base64Alphabet {
+    #[default]
+    ///Standard alphabet with `+` and `/` (RFC 4648, section 4)
+    Standard,
+    ///URL and filename safe alphabet with `-` and `_` (RFC 4648, section 5)
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    #[inline(always)]
+    const fn digits(self) -> &'static [u8; 64] {
+        match self {
+            Base64Alphabet::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Base64Alphabet::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+
+    #[inline(always)]
+    const fn last_digits(self) -> (u8, u8) {
+        match self {
+            Base64Alphabet::Standard => (b'+', b'/'),
+            Base64Alphabet::UrlSafe => (b'-', b'_'),
+        }
+    }
+}
+
+//Returns `0xFF` if `left < right`, otherwise `0`, without branching
+#[inline(always)]
+const fn mask_lt(left: u8, right: u8) -> u8 {
+    ((left as u16).wrapping_sub(right as u16) >> 8) as u8
+}
+
+//Returns `0xFF` if `left == right`, otherwise `0`, without branching
+#[inline(always)]
+const fn mask_eq(left: u8, right: u8) -> u8 {
+    mask_lt(left ^ right, 1)
+}
+
+//Maps 6 bit value to its Base64 digit using only arithmetic on the value
+#[inline(always)]
+const fn base64_digit_ct(value: u8, (digit_62, digit_63): (u8, u8)) -> u8 {
+    (mask_lt(value, 26) & value.wrapping_add(b'A'))
+    | (!mask_lt(value, 26) & mask_lt(value, 52) & value.wrapping_add(b'a' - 26))
+    | (!mask_lt(value, 52) & mask_lt(value, 62) & value.wrapping_sub(52 - b'0'))
+    | (mask_eq(value, 62) & digit_62)
+    | (mask_eq(value, 63) & digit_63)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Base64 encoding of bytes
+///
+///`ToStr` is implemented for `[u8; N]`.
+pub struct Base64<T> {
+    ///Bytes to encode
+    pub bytes: T,
+    ///Alphabet of encoding
+    pub alphabet: Base64Alphabet,
+    ///Whether to pad text with `=` up to multiple of 4 characters
+    pub padded: bool,
+    ///Whether to encode without table lookups indexed by `bytes`
+    ///
+    ///Running time and memory access pattern then depend only on the length of `bytes`,
+    ///making it suitable for secrets.
+    pub constant_time: bool,
+}
+
+impl<T> Base64<T> {
+    #[inline(always)]
+    ///Creates new instance, using padded standard alphabet
+    pub const fn new(bytes: T) -> Self {
+        Self {
+            bytes,
+            alphabet: Base64Alphabet::Standard,
+            padded: true,
+            constant_time: false,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets alphabet
+    pub const fn with_alphabet(mut self, alphabet: Base64Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets text to be written without padding
+    pub const fn unpadded(mut self) -> Self {
+        self.padded = false;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets encoding to be performed in constant time
+    pub const fn constant_time(mut self) -> Self {
+        self.constant_time = true;
+        self
+    }
+}
+
+impl<const N: usize> Base64<[u8; N]> {
+    #[inline]
+    const fn len(&self) -> usize {
+        match self.padded {
+            true => 4 * N.div_ceil(3),
+            false => (4 * N).div_ceil(3),
+        }
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let digits = self.alphabet.digits();
+        let last_digits = self.alphabet.last_digits();
+        let constant_time = self.constant_time;
+        let digit = |value: u32| {
+            let value = (value & 0x3f) as u8;
+            match constant_time {
+                true => base64_digit_ct(value, last_digits),
+                false => digits[value as usize],
+            }
+        };
+
+        let mut cursor = 0;
+        for chunk in self.bytes.chunks(3) {
+            let mut group = 0u32;
+            for (idx, byte) in chunk.iter().enumerate() {
+                group |= (*byte as u32) << (16 - 8 * idx);
+            }
+
+            //Each byte contributes to one more digit
+            for idx in 0..=chunk.len() {
+                buffer[cursor] = digit(group >> (18 - 6 * idx));
+                cursor += 1;
+            }
+        }
+
+        buffer[cursor..].fill(b'=');
+    }
+}
+
+unsafe impl<const N: usize> ToStr for Base64<[u8; N]> {
+    const TEXT_SIZE: usize = 4 * N.div_ceil(3);
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.len();
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len())?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///Alphabet of Base32 encoding
+pub enum Base32Alphabet {
+    #[default]
+    ///Standard alphabet `A-Z2-7` (RFC 4648, section 6)
+    Standard,
+    ///Extended hex alphabet `0-9A-V` (RFC 4648, section 7)
+    Hex,
+}
+
+impl Base32Alphabet {
+    #[inline(always)]
+    const fn digits(self, case: Case) -> &'static [u8; 32] {
+        match (self, case) {
+            (Base32Alphabet::Standard, Case::Upper) => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            (Base32Alphabet::Standard, Case::Lower) => b"abcdefghijklmnopqrstuvwxyz234567",
+            (Base32Alphabet::Hex, Case::Upper) => b"0123456789ABCDEFGHIJKLMNOPQRSTUV",
+            (Base32Alphabet::Hex, Case::Lower) => b"0123456789abcdefghijklmnopqrstuv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Base32 encoding of bytes
+///
+///`ToStr` is implemented for `[u8; N]`.
+pub struct Base32<T> {
+    ///Bytes to encode
+    pub bytes: T,
+    ///Alphabet of encoding
+    pub alphabet: Base32Alphabet,
+    ///Case of letters
+    pub case: Case,
+    ///Whether to pad text with `=` up to multiple of 8 characters
+    pub padded: bool,
+}
+
+impl<T> Base32<T> {
+    #[inline(always)]
+    ///Creates new instance, using padded standard alphabet in uppercase
+    pub const fn new(bytes: T) -> Self {
+        Self {
+            bytes,
+            alphabet: Base32Alphabet::Standard,
+            case: Case::Upper,
+            padded: true,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets alphabet
+    pub const fn with_alphabet(mut self, alphabet: Base32Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets case of letters
+    pub const fn with_case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets text to be written without padding
+    pub const fn unpadded(mut self) -> Self {
+        self.padded = false;
+        self
+    }
+}
+
+impl<const N: usize> Base32<[u8; N]> {
+    #[inline]
+    const fn len(&self) -> usize {
+        match self.padded {
+            true => 8 * N.div_ceil(5),
+            false => (8 * N).div_ceil(5),
+        }
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let digits = self.alphabet.digits(self.case);
+
+        let mut cursor = 0;
+        for chunk in self.bytes.chunks(5) {
+            let mut group = 0u64;
+            for (idx, byte) in chunk.iter().enumerate() {
+                group |= (*byte as u64) << (32 - 8 * idx);
+            }
+
+            for idx in 0..(8 * chunk.len()).div_ceil(5) {
+                buffer[cursor] = digits[((group >> (35 - 5 * idx)) & 0x1f) as usize];
+                cursor += 1;
+            }
+        }
+
+        buffer[cursor..].fill(b'=');
+    }
+}
+
+impl_to_str!(Base32: 8 * N.div_ceil(5));
+
+static BASE58_DIGITS: [u8; 58] = *b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+//Number of Base58 digits in single division chunk
+const BASE58_CHUNK_DIGITS: usize = 9;
+//Greatest power of 58, such that `rem * 256 + 255` still fits `u64`
+const BASE58_CHUNK: u64 = 58u64.pow(BASE58_CHUNK_DIGITS as u32);
+
+//Divides big endian number in place by `BASE58_CHUNK`, returning remainder.
+//
+//Schoolbook long division, one byte at a time.
+#[inline]
+fn udivmod_base58_chunk(num: &mut [u8]) -> u64 {
+    let mut rem = 0u64;
+    for byte in num.iter_mut() {
+        let acc = (rem << 8) | *byte as u64;
+        *byte = (acc / BASE58_CHUNK) as u8;
+        rem = acc % BASE58_CHUNK;
+    }
+    rem
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Base58 encoding of bytes using Bitcoin alphabet
+///
+///Every leading zero byte is written as `1`.
+///`TEXT_SIZE` is worst case, while actual length depends on value of bytes.
+///
+///Encoding takes time quadratic in `N`, and `text_len()` performs complete encoding without writing.
+///`try_to_str()` encodes only once, counting remaining digits if buffer is exceeded.
+///
+///`ToStr` is implemented for `[u8; N]`.
+pub struct Base58<T> {
+    ///Bytes to encode
+    pub bytes: T,
+}
+
+impl<T> Base58<T> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(bytes: T) -> Self {
+        Self {
+            bytes,
+        }
+    }
+}
+
+impl<const N: usize> Base58<[u8; N]> {
+    //Writes digits from the end of `buffer` as long as they fit, returning total number of digits.
+    #[inline]
+    fn encode(&self, buffer: &mut [u8]) -> usize {
+        let zeros = self.bytes.iter().take_while(|byte| **byte == 0).count();
+        let mut num = self.bytes;
+        let mut start = zeros;
+        let mut len = 0;
+
+        let mut put = |digit: u8, len: &mut usize| {
+            *len += 1;
+            if let Some(cursor) = buffer.len().checked_sub(*len) {
+                buffer[cursor] = digit;
+            }
+        };
+
+        while start < N {
+            let mut rem = udivmod_base58_chunk(&mut num[start..]);
+            while start < N && num[start] == 0 {
+                start += 1;
+            }
+
+            //Most significant chunk is written without leading zeros
+            if start == N {
+                while rem != 0 {
+                    put(BASE58_DIGITS[(rem % 58) as usize], &mut len);
+                    rem /= 58;
+                }
+            } else {
+                for _ in 0..BASE58_CHUNK_DIGITS {
+                    put(BASE58_DIGITS[(rem % 58) as usize], &mut len);
+                    rem /= 58;
+                }
+            }
+        }
+
+        for _ in 0..zeros {
+            put(BASE58_DIGITS[0], &mut len);
+        }
+
+        len
+    }
+}
+
+unsafe impl<const N: usize> ToStr for Base58<[u8; N]> {
+    //Each byte requires at most log(256) / log(58) < 1.38 digits
+    const TEXT_SIZE: usize = (N * 138).div_ceil(100);
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.encode(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(&buffer[start..])
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.encode(&mut [])
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let len = self.encode(buffer);
+        let buffer = buffer_tail(buffer, len)?;
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
+
+static Z85_DIGITS: [u8; 85] = *b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Z85 encoding of bytes as specified by ZeroMQ RFC 32
+///
+///`ToStr` is implemented for `[u8; N]` with `N` multiple of 4, otherwise it fails to compile.
+///
+///```compile_fail
+///use to_str::{Buffer, ToStr};
+///use to_str::encoding::Z85;
+///
+///let _ = <Z85<[u8; 3]>>::TEXT_SIZE;
+///```
+pub struct Z85<T> {
+    ///Bytes to encode
+    pub bytes: T,
+}
+
+impl<T> Z85<T> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(bytes: T) -> Self {
+        Self {
+            bytes,
+        }
+    }
+}
+
+impl<const N: usize> Z85<[u8; N]> {
+    #[inline(always)]
+    const fn len(&self) -> usize {
+        N / 4 * 5
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        for (chunk, out) in self.bytes.chunks_exact(4).zip(buffer.chunks_exact_mut(5)) {
+            let mut num = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            for digit in out.iter_mut().rev() {
+                *digit = Z85_DIGITS[(num % 85) as usize];
+                num /= 85;
+            }
+        }
+    }
+}
+
+impl_to_str!(Z85: {
+    assert!(N % 4 == 0, "Z85 requires number of bytes to be multiple of 4");
+    N / 4 * 5
+});
+
+//Builds ASCII bitmask of characters to encode on top of controls, space, non-ASCII and `%`
+const fn ascii_set(chars: &[u8]) -> u128 {
+    //C0 controls, space, `%` and DEL
+    let mut set = ((1u128 << 0x21) - 1) | (1 << b'%') | (1 << 0x7f);
+    let mut idx = 0;
+    while idx < chars.len() {
+        set |= 1 << chars[idx];
+        idx += 1;
+    }
+    set
+}
+
+const QUERY_SET: &[u8] = b"\"#<>";
+const PATH_SET: &[u8] = b"\"#<>?^`{}";
+const USERINFO_SET: &[u8] = b"\"#<>?`{}/:;=@[\\]^|";
+const COMPONENT_SET: &[u8] = b"\"#<>?`{}/:;=@[\\]^|$&+,";
+const FORM_SET: &[u8] = b"\"#<>?`{}/:;=@[\\]^|$&+,!'()~";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///Set of characters to percent-encode as defined by WHATWG URL standard
+///
+///Controls, space, non-ASCII bytes and `%` itself are encoded in every set, so text always decodes back into original bytes.
+pub enum EncodeSet {
+    ///Path percent-encode set, which leaves `/` as is
+    Path,
+    ///Query percent-encode set, which leaves `&`, `=` and `+` as is
+    ///
+    ///Suitable for complete query, rather than its individual keys and values
+    Query,
+    ///Userinfo percent-encode set
+    Userinfo,
+    #[default]
+    ///Component percent-encode set, suitable for individual keys and values
+    Component,
+    ///`application/x-www-form-urlencoded` set, writing space as `+`
+    Form,
+}
+
+impl EncodeSet {
+    #[inline(always)]
+    const fn ascii_set(self) -> u128 {
+        match self {
+            EncodeSet::Path => const { ascii_set(PATH_SET) },
+            EncodeSet::Query => const { ascii_set(QUERY_SET) },
+            EncodeSet::Userinfo => const { ascii_set(USERINFO_SET) },
+            EncodeSet::Component => const { ascii_set(COMPONENT_SET) },
+            //Space is written as `+`, taking single character
+            EncodeSet::Form => const { ascii_set(FORM_SET) & !(1 << b' ') },
+        }
+    }
+
+    #[inline(always)]
+    const fn encoded_len(ascii_set: u128, byte: u8) -> usize {
+        if byte >= 0x80 || ascii_set & (1 << byte) != 0 {
+            3
+        } else {
+            1
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Percent-encoding of bytes using uppercase hex digits
+///
+///`ToStr` is implemented for `[u8; N]`, as well as text of `FmtBuffer<N>` and `Buffer<N>`.
+pub struct PercentEncoded<T> {
+    ///Value to encode
+    pub value: T,
+    ///Set of characters to encode
+    pub set: EncodeSet,
+}
+
+impl<T> PercentEncoded<T> {
+    #[inline(always)]
+    ///Creates new instance, using `Component` set
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            set: EncodeSet::Component,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets set of characters to encode
+    pub const fn with_set(mut self, set: EncodeSet) -> Self {
+        self.set = set;
+        self
+    }
+}
+
+//Length of percent-encoded `bytes`
+#[inline]
+fn percent_len(bytes: &[u8], set: EncodeSet) -> usize {
+    let ascii_set = set.ascii_set();
+    bytes.iter().map(|byte| EncodeSet::encoded_len(ascii_set, *byte)).sum()
+}
+
+//Writes percent-encoded `bytes`, filling whole `buffer` of `percent_len()`
+#[inline]
+fn write_percent(bytes: &[u8], set: EncodeSet, buffer: &mut [u8]) {
+    let pairs = Case::Upper.hex_pairs();
+    let ascii_set = set.ascii_set();
+
+    let mut cursor = 0;
+    for byte in bytes {
+        if set == EncodeSet::Form && *byte == b' ' {
+            buffer[cursor] = b'+';
+            cursor += 1;
+        } else if EncodeSet::encoded_len(ascii_set, *byte) == 3 {
+            let byte = *byte as usize;
+            buffer[cursor] = b'%';
+            buffer[cursor + 1] = pairs[byte * 2];
+            buffer[cursor + 2] = pairs[byte * 2 + 1];
+            cursor += 3;
+        } else {
+            buffer[cursor] = *byte;
+            cursor += 1;
+        }
+    }
+}
+
+macro_rules! impl_percent_encoded {
+    ($($t:ty => |$this:ident| $bytes:expr;)+) => {
+        $(
+            impl<const N: usize> PercentEncoded<$t> {
+                #[inline(always)]
+                fn len(&self) -> usize {
+                    let $this = &self.value;
+                    percent_len($bytes, self.set)
+                }
+
+                //Writes exactly `len()` bytes into `buffer`
+                #[inline(always)]
+                fn write(&self, buffer: &mut [u8]) {
+                    let $this = &self.value;
+                    write_percent($bytes, self.set, buffer)
+                }
+            }
+
+            impl_to_str!(PercentEncoded<$t>: 3 * N);
+        )+
+    };
+}
+
+impl_percent_encoded!(
+    [u8; N] => |this| this;
+    FmtBuffer<N> => |this| this.as_str().as_bytes();
+    Buffer<N> => |this| this.as_str().as_bytes();
+);
@@ -0,0 +1,57 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Formatting error
+pub enum Error {
+    ///Buffer is not sufficient to hold text
+    BufferTooSmall {
+        ///Number of bytes required to write text, as reported by `text_len()`
+        required: usize,
+        ///Number of bytes available in the buffer
+        available: usize,
+    },
+    ///Value has no acceptable textual representation, regardless of buffer size
+    Unrepresentable,
+}
+
+impl fmt::Display for Error {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall { required, available } => fmt.write_fmt(format_args!("Buffer too small: requires {required} bytes, but only {available} available")),
+            Self::Unrepresentable => fmt.write_str("Value has no acceptable textual representation"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error parsing text back into value
+pub enum ParseError {
+    ///Text length doesn't match any accepted form
+    InvalidLength {
+        ///Length of the text
+        len: usize,
+    },
+    ///Unexpected character in the text
+    InvalidCharacter {
+        ///Byte index of the character
+        index: usize,
+    },
+    ///Value doesn't fit into the target type
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength { len } => fmt.write_fmt(format_args!("Invalid length {len}")),
+            Self::InvalidCharacter { index } => fmt.write_fmt(format_args!("Invalid character at index {index}")),
+            Self::Overflow => fmt.write_str("Value overflows target type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
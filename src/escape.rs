@@ -0,0 +1,131 @@
+//!Escaped forms of `char`
+//!
+//!Each wrapper writes single character in its escaped or code point notation.
+//!Hexadecimal digits are uppercase, except for JSON escapes which use lowercase digits as is customary.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::escape::{CodePoint, EscapeUnicode, HtmlHex, HtmlDecimal, JsonEscape};
+//!
+//!assert_eq!(Buffer::<16>::fmt(CodePoint('😀')).as_str(), "U+1F600");
+//!assert_eq!(Buffer::<16>::fmt(EscapeUnicode('😀')).as_str(), "\\u{1F600}");
+//!assert_eq!(Buffer::<16>::fmt(HtmlHex('😀')).as_str(), "&#x1F600;");
+//!assert_eq!(Buffer::<16>::fmt(HtmlDecimal('😀')).as_str(), "&#128512;");
+//!assert_eq!(Buffer::<16>::fmt(JsonEscape('😀')).as_str(), "\\ud83d\\ude00");
+//!```
+
+use crate::{buffer_tail, Error, ToStr};
+use crate::numeric::{decimal_len, hex_len_u32, write_hex_u32_to_buf, write_u64_to_buf, HEX_DIGITS, HEX_DIGITS_UPPER};
+use crate::parts::unwrap_text;
+
+//Writes `prefix`, hex digits of `num` and `suffix` as exact tail of the `buffer`
+fn write_hex<'a>(buffer: &'a mut [u8], prefix: &str, num: u32, digits: &[u8; 16], min_len: usize, suffix: &str) -> Result<&'a str, Error> {
+    let len = prefix.len() + hex_len_u32(num, min_len) + suffix.len();
+    let buffer = buffer_tail(buffer, len)?;
+    let cursor = len - suffix.len();
+    buffer[cursor..].copy_from_slice(suffix.as_bytes());
+    unsafe {
+        write_hex_u32_to_buf(num, digits, min_len, buffer.as_mut_ptr(), cursor as isize);
+    }
+    buffer[..prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    Ok(unsafe {
+        core::str::from_utf8_unchecked(buffer)
+    })
+}
+
+macro_rules! impl_escape {
+    ($($(#[$meta:meta])* $name:ident: $size:expr => { text_len: |$len_ch:ident| $len:expr, try_to_str: |$ch:ident, $buffer:ident| $write:expr, })+) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            $(#[$meta])*
+            pub struct $name(pub char);
+
+            unsafe impl ToStr for $name {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    unwrap_text(self.try_to_str(buffer))
+                }
+
+                #[inline]
+                fn text_len(&self) -> usize {
+                    let $len_ch = self.0;
+                    $len
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, $buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let $ch = self.0;
+                    $write
+                }
+            }
+        )+
+    };
+}
+
+impl_escape!(
+    ///Character escaped as per `char::escape_debug`
+    ///
+    ///Longest text is `\u{10ffff}`.
+    EscapeDebug: 10 => {
+        text_len: |ch| ch.escape_debug().map(char::len_utf8).sum(),
+        try_to_str: |ch, buffer| {
+            let len = EscapeDebug(ch).text_len();
+            let buffer = buffer_tail(buffer, len)?;
+            let mut cursor = 0;
+            for part in ch.escape_debug() {
+                cursor += part.encode_utf8(&mut buffer[cursor..]).len();
+            }
+            Ok(unsafe {
+                core::str::from_utf8_unchecked(buffer)
+            })
+        },
+    }
+    ///Unicode code point notation `U+XXXX` with at least 4 digits
+    CodePoint: 8 => {
+        text_len: |ch| 2 + hex_len_u32(ch as u32, 4),
+        try_to_str: |ch, buffer| write_hex(buffer, "U+", ch as u32, &HEX_DIGITS_UPPER, 4, ""),
+    }
+    ///Rust unicode escape `\u{XXXX}`
+    EscapeUnicode: 10 => {
+        text_len: |ch| 4 + hex_len_u32(ch as u32, 1),
+        try_to_str: |ch, buffer| write_hex(buffer, "\\u{", ch as u32, &HEX_DIGITS_UPPER, 1, "}"),
+    }
+    ///HTML/XML hexadecimal character reference `&#xXXXX;`
+    HtmlHex: 10 => {
+        text_len: |ch| 4 + hex_len_u32(ch as u32, 1),
+        try_to_str: |ch, buffer| write_hex(buffer, "&#x", ch as u32, &HEX_DIGITS_UPPER, 1, ";"),
+    }
+    ///HTML/XML decimal character reference `&#NNNN;`
+    HtmlDecimal: 10 => {
+        text_len: |ch| 3 + decimal_len::u32(ch as u32),
+        try_to_str: |ch, buffer| {
+            let len = HtmlDecimal(ch).text_len();
+            let buffer = buffer_tail(buffer, len)?;
+            buffer[..2].copy_from_slice(b"&#");
+            buffer[len - 1] = b';';
+            unsafe {
+                write_u64_to_buf(ch as u64, buffer.as_mut_ptr(), len as isize - 1);
+                Ok(core::str::from_utf8_unchecked(buffer))
+            }
+        },
+    }
+    ///JSON escape `\uxxxx`, using surrogate pair outside of Basic Multilingual Plane
+    JsonEscape: 12 => {
+        text_len: |ch| 6 * ch.len_utf16(),
+        try_to_str: |ch, buffer| {
+            let mut units = [0u16; 2];
+            let units = ch.encode_utf16(&mut units);
+            let len = 6 * units.len();
+            let buffer = buffer_tail(buffer, len)?;
+            for (unit, chunk) in units.iter().zip(buffer.chunks_exact_mut(6)) {
+                write_hex(chunk, "\\u", *unit as u32, &HEX_DIGITS, 4, "")?;
+            }
+            Ok(unsafe {
+                core::str::from_utf8_unchecked(buffer)
+            })
+        },
+    }
+);
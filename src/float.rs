@@ -0,0 +1,127 @@
+use crate::{buffer_tail, Error, FmtBuffer, ToStr};
+use crate::parts::unwrap_text;
+
+use core::fmt;
+
+//Longest text of `f64`, either `-0.0000` followed by 17 digits, or `-1.2345678901234567e-308`
+const MAX_LEN: usize = 24;
+
+//Text of float, laid out from the shortest round-trip digits produced by `LowerExp`
+struct FloatText {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl FloatText {
+    fn new<T: fmt::LowerExp>(value: T) -> Self {
+        let mut exp = FmtBuffer::<32>::new();
+        let _ = fmt::Write::write_fmt(&mut exp, format_args!("{value:e}"));
+        let exp = exp.as_str();
+
+        let mut this = Self {
+            bytes: [0; MAX_LEN],
+            len: 0,
+        };
+
+        let (mantissa, exponent) = match exp.split_once('e') {
+            Some((mantissa, exponent)) => match exponent.parse::<i32>() {
+                Ok(exponent) if (-5..=16).contains(&exponent) => (mantissa, exponent),
+                _ => {
+                    this.push(exp.as_bytes());
+                    return this;
+                },
+            },
+            //`NaN`, `inf` and `-inf`
+            None => {
+                this.push(exp.as_bytes());
+                return this;
+            },
+        };
+
+        let (sign, mantissa) = match mantissa.strip_prefix('-') {
+            Some(mantissa) => ("-", mantissa),
+            None => ("", mantissa),
+        };
+        this.push(sign.as_bytes());
+
+        let mut digits = [0u8; 17];
+        let mut digits_len = 0;
+        for digit in mantissa.bytes().filter(|byte| *byte != b'.') {
+            digits[digits_len] = digit;
+            digits_len += 1;
+        }
+        let digits = &digits[..digits_len];
+
+        if exponent < 0 {
+            this.push(b"0.");
+            for _ in 0..-exponent - 1 {
+                this.push(b"0");
+            }
+            this.push(digits);
+        } else {
+            let int_len = exponent as usize + 1;
+            if digits.len() > int_len {
+                this.push(&digits[..int_len]);
+                this.push(b".");
+                this.push(&digits[int_len..]);
+            } else {
+                this.push(digits);
+                for _ in digits.len()..int_len {
+                    this.push(b"0");
+                }
+            }
+        }
+
+        this
+    }
+
+    #[inline(always)]
+    fn push(&mut self, text: &[u8]) {
+        self.bytes[self.len..self.len + text.len()].copy_from_slice(text);
+        self.len += text.len();
+    }
+
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+macro_rules! impl_float {
+    ($($t:ty: $size:expr;)+) => {
+        $(
+            ///Written the same as `Display` when absolute value is zero or within `1e-5..1e17`, otherwise as `LowerExp`.
+            ///
+            ///Digits are the shortest ones which round-trip, as produced by `core::fmt`.
+            unsafe impl ToStr for $t {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    unwrap_text(self.try_to_str(buffer))
+                }
+
+                #[inline]
+                fn text_len(&self) -> usize {
+                    FloatText::new(*self).len
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let text = FloatText::new(*self);
+                    let buffer = buffer_tail(buffer, text.len)?;
+                    buffer.copy_from_slice(text.as_bytes());
+                    Ok(unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_float!(
+    //Either `-` followed by 17 digits, or `-0.0000` followed by 9 digits
+    f32: 18;
+    f64: MAX_LEN;
+);
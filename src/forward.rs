@@ -0,0 +1,85 @@
+use crate::{Error, ToStr};
+
+use core::mem::MaybeUninit;
+use core::{cell, cmp, num};
+use core::sync::atomic::{self, Ordering};
+
+//Implements `ToStr` by forwarding all methods to the value
+macro_rules! forward_to_str {
+    ($size:expr => |$this:ident| $value:expr) => {
+        const TEXT_SIZE: usize = $size;
+
+        #[inline(always)]
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let $this = self;
+            ToStr::to_str(&$value, buffer)
+        }
+
+        #[inline(always)]
+        fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+            let $this = self;
+            ToStr::to_str_uninit(&$value, buffer)
+        }
+
+        #[inline(always)]
+        fn text_len(&self) -> usize {
+            let $this = self;
+            ToStr::text_len(&$value)
+        }
+
+        #[inline(always)]
+        fn is_integer(&self) -> bool {
+            let $this = self;
+            ToStr::is_integer(&$value)
+        }
+
+        #[inline(always)]
+        fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+            let $this = self;
+            ToStr::try_to_str(&$value, buffer)
+        }
+
+        #[inline(always)]
+        fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+            let $this = self;
+            ToStr::to_str_front(&$value, buffer)
+        }
+    };
+}
+
+unsafe impl<T: ToStr> ToStr for num::Wrapping<T> {
+    forward_to_str!(T::TEXT_SIZE => |this| this.0);
+}
+
+unsafe impl<T: ToStr> ToStr for num::Saturating<T> {
+    forward_to_str!(T::TEXT_SIZE => |this| this.0);
+}
+
+unsafe impl<T: ToStr> ToStr for cmp::Reverse<T> {
+    forward_to_str!(T::TEXT_SIZE => |this| this.0);
+}
+
+unsafe impl<T: Copy + ToStr> ToStr for cell::Cell<T> {
+    forward_to_str!(T::TEXT_SIZE => |this| this.get());
+}
+
+macro_rules! impl_atomic {
+    ($($width:literal: $($atomic:ident($t:ty)),+;)+) => {
+        $(
+            $(
+                #[cfg(target_has_atomic = $width)]
+                unsafe impl ToStr for atomic::$atomic {
+                    forward_to_str!(<$t as ToStr>::TEXT_SIZE => |this| this.load(Ordering::Acquire));
+                }
+            )+
+        )+
+    };
+}
+
+impl_atomic!(
+    "8": AtomicBool(bool), AtomicU8(u8), AtomicI8(i8);
+    "16": AtomicU16(u16), AtomicI16(i16);
+    "32": AtomicU32(u32), AtomicI32(i32);
+    "64": AtomicU64(u64), AtomicI64(i64);
+    "ptr": AtomicUsize(usize), AtomicIsize(isize);
+);
@@ -0,0 +1,181 @@
+//!Canonical hex dump as written by `hexdump -C`
+//!
+//!Repeated lines are not squeezed into `*`, same as with `hexdump -C -v`.
+//!
+//!```
+//!use to_str::{Buffer, ToStr};
+//!use to_str::hexdump::{HexDump, HexDumpLine};
+//!
+//!let mut buffer = Buffer::<{ HexDumpLine::TEXT_SIZE }>::new();
+//!let mut lines = HexDump::new(b"Hello world\n").with_offset(0x10);
+//!assert_eq!(buffer.format(lines.next().unwrap()), "00000010  48 65 6c 6c 6f 20 77 6f  72 6c 64 0a              |Hello world.|");
+//!assert!(lines.next().is_none());
+//!```
+
+use crate::{buffer_tail, Case, Error, ToStr};
+use crate::numeric::{hex_len, write_hex_to_buf, HEX_DIGITS};
+
+//Width of bytes column: 16 bytes written as `xx ` with extra space in the middle, except trailing space
+const HEX_WIDTH: usize = 16 * 3 + 1 - 1;
+//Minimal number of digits in offset
+const OFFSET_MIN_LEN: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Single line of hex dump with up to 16 bytes
+///
+///Written as offset of at least 8 hex digits, bytes in hex and printable ASCII characters, with `.` in place of others.
+pub struct HexDumpLine {
+    offset: usize,
+    bytes: [u8; 16],
+    len: u8,
+}
+
+impl HexDumpLine {
+    ///Maximum number of bytes in a line
+    pub const WIDTH: usize = 16;
+
+    #[inline]
+    ///Creates new instance, returning `None` if there is more than `WIDTH` bytes
+    pub const fn new(offset: usize, bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > Self::WIDTH {
+            return None;
+        }
+
+        let mut this = Self {
+            offset,
+            bytes: [0; 16],
+            len: bytes.len() as u8,
+        };
+        let mut idx = 0;
+        while idx < bytes.len() {
+            this.bytes[idx] = bytes[idx];
+            idx += 1;
+        }
+        Some(this)
+    }
+
+    #[inline(always)]
+    ///Returns offset of the first byte
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline(always)]
+    ///Returns bytes of the line
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        hex_len(self.offset, OFFSET_MIN_LEN) + 2 + HEX_WIDTH + 3 + self.len as usize + 1
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let pairs = Case::Lower.hex_pairs();
+        let offset_len = hex_len(self.offset, OFFSET_MIN_LEN);
+        unsafe {
+            write_hex_to_buf(self.offset, &HEX_DIGITS, OFFSET_MIN_LEN, buffer.as_mut_ptr(), offset_len as isize);
+        }
+
+        let (hex, text) = buffer[offset_len..].split_at_mut(2 + HEX_WIDTH + 3);
+        hex.fill(b' ');
+        for (idx, byte) in self.bytes().iter().enumerate() {
+            let cursor = 2 + idx * 3 + idx / 8;
+            let byte = *byte as usize;
+            hex[cursor] = pairs[byte * 2];
+            hex[cursor + 1] = pairs[byte * 2 + 1];
+        }
+        hex[hex.len() - 1] = b'|';
+
+        for (out, byte) in text.iter_mut().zip(self.bytes()) {
+            *out = match byte {
+                b' '..=b'~' => *byte,
+                _ => b'.',
+            };
+        }
+        text[text.len() - 1] = b'|';
+    }
+}
+
+unsafe impl ToStr for HexDumpLine {
+    const TEXT_SIZE: usize = usize::BITS as usize / 4 + 2 + HEX_WIDTH + 3 + Self::WIDTH + 1;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.len();
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len())?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+///Iterator over hex dump lines of the slice
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    #[inline(always)]
+    ///Creates new instance, starting at offset `0`
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets offset of the first byte
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Iterator for HexDump<'_> {
+    type Item = HexDumpLine;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = self.bytes.split_at(self.bytes.len().min(HexDumpLine::WIDTH));
+        let line = HexDumpLine::new(self.offset, line)?;
+        self.bytes = rest;
+        self.offset = self.offset.wrapping_add(HexDumpLine::WIDTH);
+        Some(line)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len().div_ceil(HexDumpLine::WIDTH);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for HexDump<'_> {}
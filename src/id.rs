@@ -0,0 +1,434 @@
+//!Formatting and parsing of identifiers
+//!
+//!```
+//!use to_str::{Buffer, Case};
+//!use to_str::id::Uuid;
+//!
+//!const ID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+//!
+//!assert_eq!(Buffer::<36>::fmt(ID).as_str(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+//!assert_eq!(Buffer::<45>::fmt(ID.urn().with_case(Case::Upper)).as_str(), "urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8");
+//!assert_eq!(Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c8}"), Ok(ID));
+//!```
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::id::Ulid;
+//!
+//!let id = Ulid::from_parts(1469922850259, 0xd676_4c61_efb9_9302_bd5b);
+//!assert_eq!(Buffer::<26>::fmt(id).as_str(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+//!assert_eq!(Ulid::parse_str("01arz3ndektsv4rrffq69g5fav"), Ok(id));
+//!```
+
+use crate::{buffer_tail, Case, Error, ParseError, ToStr};
+
+use core::str::FromStr;
+
+//Value of hex digit in either case
+const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+///Universally unique identifier
+///
+///Written in lowercase hyphenated form, other forms are available via `simple()`, `urn()` and `braced()`.
+pub struct Uuid(u128);
+
+impl Uuid {
+    ///Nil UUID with all bits set to zero
+    pub const NIL: Self = Self(0);
+    ///Max UUID with all bits set to one
+    pub const MAX: Self = Self(u128::MAX);
+
+    #[inline(always)]
+    ///Creates new instance from its integer value
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    ///Creates new instance from bytes in big endian order
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    #[inline(always)]
+    ///Returns integer value
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+
+    #[inline(always)]
+    ///Returns bytes in big endian order
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    #[inline(always)]
+    ///Returns hyphenated form `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    pub const fn hyphenated(self) -> Hyphenated {
+        Hyphenated::new(self)
+    }
+
+    #[inline(always)]
+    ///Returns simple form `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`
+    pub const fn simple(self) -> Simple {
+        Simple::new(self)
+    }
+
+    #[inline(always)]
+    ///Returns URN form `urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    pub const fn urn(self) -> Urn {
+        Urn::new(self)
+    }
+
+    #[inline(always)]
+    ///Returns braced form `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`
+    pub const fn braced(self) -> Braced {
+        Braced::new(self)
+    }
+
+    ///Parses any of hyphenated, simple, URN or braced forms in either case
+    ///
+    ///URN prefix `urn:uuid:` is case-insensitive as well.
+    pub const fn parse_str(text: &str) -> Result<Self, ParseError> {
+        const URN_PREFIX: &[u8] = b"urn:uuid:";
+
+        let bytes = text.as_bytes();
+        //Range of hex digits and hyphens
+        let (start, end) = match bytes.len() {
+            32 | 36 => (0, bytes.len()),
+            38 => {
+                if bytes[0] != b'{' {
+                    return Err(ParseError::InvalidCharacter { index: 0 });
+                } else if bytes[37] != b'}' {
+                    return Err(ParseError::InvalidCharacter { index: 37 });
+                }
+                (1, 37)
+            },
+            45 => {
+                let mut idx = 0;
+                while idx < URN_PREFIX.len() {
+                    if !bytes[idx].eq_ignore_ascii_case(&URN_PREFIX[idx]) {
+                        return Err(ParseError::InvalidCharacter { index: idx });
+                    }
+                    idx += 1;
+                }
+                (URN_PREFIX.len(), 45)
+            },
+            len => return Err(ParseError::InvalidLength { len }),
+        };
+
+        let hyphenated = end - start == 36;
+        let mut value = 0u128;
+        let mut idx = start;
+        while idx < end {
+            let offset = idx - start;
+            if hyphenated && (offset == 8 || offset == 13 || offset == 18 || offset == 23) {
+                if bytes[idx] != b'-' {
+                    return Err(ParseError::InvalidCharacter { index: idx });
+                }
+            } else {
+                match hex_value(bytes[idx]) {
+                    Some(digit) => value = (value << 4) | digit as u128,
+                    None => return Err(ParseError::InvalidCharacter { index: idx }),
+                }
+            }
+            idx += 1;
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<u128> for Uuid {
+    #[inline(always)]
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    #[inline(always)]
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(text)
+    }
+}
+
+//Writes hex digits of `uuid` into `buffer`, starting from the end.
+fn write_uuid(uuid: Uuid, digits: &[u8; 16], hyphenated: bool, buffer: &mut [u8]) {
+    let mut num = uuid.0;
+    let mut cursor = buffer.len();
+    for idx in 0..32 {
+        if hyphenated && (idx == 12 || idx == 16 || idx == 20 || idx == 24) {
+            cursor -= 1;
+            buffer[cursor] = b'-';
+        }
+        cursor -= 1;
+        buffer[cursor] = digits[(num & 0xf) as usize];
+        num >>= 4;
+    }
+}
+
+macro_rules! impl_form {
+    ($($(#[$meta:meta])* $name:ident: $prefix:literal $hyphenated:literal $suffix:literal;)+) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            $(#[$meta])*
+            pub struct $name {
+                ///Identifier
+                pub uuid: Uuid,
+                ///Case of hex digits
+                pub case: Case,
+            }
+
+            impl $name {
+                #[inline(always)]
+                ///Creates new instance with lowercase digits
+                pub const fn new(uuid: Uuid) -> Self {
+                    Self {
+                        uuid,
+                        case: Case::Lower,
+                    }
+                }
+
+                #[inline(always)]
+                ///Sets case of hex digits
+                pub const fn with_case(mut self, case: Case) -> Self {
+                    self.case = case;
+                    self
+                }
+
+                #[inline]
+                fn write(&self, buffer: &mut [u8]) {
+                    let digits_end = buffer.len() - $suffix.len();
+                    buffer[digits_end..].copy_from_slice($suffix.as_bytes());
+                    write_uuid(self.uuid, self.case.hex_digits(), $hyphenated, &mut buffer[..digits_end]);
+                    buffer[..$prefix.len()].copy_from_slice($prefix.as_bytes());
+                }
+            }
+
+            unsafe impl ToStr for $name {
+                const TEXT_SIZE: usize = $prefix.len() + if $hyphenated { 36 } else { 32 } + $suffix.len();
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+                    let start = buffer.len() - Self::TEXT_SIZE;
+                    let buffer = &mut buffer[start..];
+                    self.write(buffer);
+                    unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    }
+                }
+
+                #[inline(always)]
+                fn text_len(&self) -> usize {
+                    Self::TEXT_SIZE
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let buffer = buffer_tail(buffer, Self::TEXT_SIZE)?;
+                    self.write(buffer);
+                    Ok(unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_form!(
+    ///UUID in hyphenated form `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    Hyphenated: "" true "";
+    ///UUID in simple form `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`
+    Simple: "" false "";
+    ///UUID in URN form `urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    Urn: "urn:uuid:" true "";
+    ///UUID in braced form `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`
+    Braced: "{" true "}";
+);
+
+unsafe impl ToStr for Uuid {
+    const TEXT_SIZE: usize = Hyphenated::TEXT_SIZE;
+
+    #[inline(always)]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        self.hyphenated().to_str(buffer)
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        Self::TEXT_SIZE
+    }
+
+    #[inline(always)]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        self.hyphenated().try_to_str(buffer)
+    }
+}
+
+//Crockford base32 alphabet, excluding `I`, `L`, `O` and `U`
+static CROCKFORD_DIGITS: [u8; 32] = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+//Value of Crockford base32 digit in either case, including aliases of `0` and `1`
+const fn crockford_value(byte: u8) -> Option<u8> {
+    let value = match byte.to_ascii_uppercase() {
+        byte @ b'0'..=b'9' => byte - b'0',
+        b'O' => 0,
+        b'I' | b'L' => 1,
+        byte @ b'A'..=b'H' => byte - b'A' + 10,
+        b'J' => 18,
+        b'K' => 19,
+        b'M' => 20,
+        b'N' => 21,
+        byte @ b'P'..=b'T' => byte - b'P' + 22,
+        byte @ b'V'..=b'Z' => byte - b'V' + 27,
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+///Universally unique lexicographically sortable identifier
+///
+///Consists of 48 bit timestamp in milliseconds followed by 80 random bits.
+///Written as 26 characters of Crockford base32 in uppercase.
+pub struct Ulid(u128);
+
+impl Ulid {
+    ///Number of bits in random part
+    const RANDOM_BITS: u32 = 80;
+    ///Number of characters in text
+    const LEN: usize = 26;
+
+    #[inline(always)]
+    ///Creates new instance from its integer value
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    ///Creates new instance from timestamp and random part, truncating each to its bit width
+    pub const fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        let timestamp = (timestamp_ms as u128 & ((1 << 48) - 1)) << Self::RANDOM_BITS;
+        Self(timestamp | (random & ((1 << Self::RANDOM_BITS) - 1)))
+    }
+
+    #[inline(always)]
+    ///Returns integer value
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+
+    #[inline(always)]
+    ///Returns timestamp in milliseconds
+    pub const fn timestamp_ms(&self) -> u64 {
+        (self.0 >> Self::RANDOM_BITS) as u64
+    }
+
+    #[inline(always)]
+    ///Returns random part
+    pub const fn random(&self) -> u128 {
+        self.0 & ((1 << Self::RANDOM_BITS) - 1)
+    }
+
+    ///Parses Crockford base32 text in either case
+    ///
+    ///Aliases `I` and `L` are accepted as `1`, while `O` is accepted as `0`.
+    pub const fn parse_str(text: &str) -> Result<Self, ParseError> {
+        let bytes = text.as_bytes();
+        if bytes.len() != Self::LEN {
+            return Err(ParseError::InvalidLength { len: bytes.len() });
+        }
+
+        let mut value = 0u128;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            match crockford_value(bytes[idx]) {
+                //26 digits hold 130 bits, therefore first digit may only use 3 bits
+                Some(digit) if idx == 0 && digit > 7 => return Err(ParseError::Overflow),
+                Some(digit) => value = (value << 5) | digit as u128,
+                None => return Err(ParseError::InvalidCharacter { index: idx }),
+            }
+            idx += 1;
+        }
+
+        Ok(Self(value))
+    }
+
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let mut num = self.0;
+        for byte in buffer.iter_mut().rev() {
+            *byte = CROCKFORD_DIGITS[(num & 0x1f) as usize];
+            num >>= 5;
+        }
+    }
+}
+
+impl From<u128> for Ulid {
+    #[inline(always)]
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for Ulid {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(text)
+    }
+}
+
+unsafe impl ToStr for Ulid {
+    const TEXT_SIZE: usize = Self::LEN;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - Self::TEXT_SIZE;
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        Self::TEXT_SIZE
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, Self::TEXT_SIZE)?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
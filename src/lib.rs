@@ -19,10 +19,41 @@
 #![allow(clippy::style)]
 #![no_std]
 
+use core::mem::MaybeUninit;
+
 mod buffer;
-mod numeric;
+mod capture;
+mod display;
+mod dynamic;
+mod error;
+mod float;
+pub mod encoding;
+pub mod escape;
+mod forward;
+pub mod hexdump;
+pub mod id;
+pub mod mac;
+pub mod net;
+pub mod numeric;
+pub mod option;
+mod parts;
+mod primitive;
+mod range;
+mod result;
+pub mod roman;
+pub mod seq;
+pub mod sheet;
+pub mod sortable;
+pub mod sqids;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use buffer::Buffer;
+pub use capture::{Bounded, FmtBuffer, Truncation};
+pub use display::Displayed;
+pub use dynamic::{AnyNum, DynToStr};
+pub use error::{Error, ParseError};
+pub use numeric::Case;
 
 ///Alias to buffer that can be used to write `8` bit integers
 pub type Buffer8 = Buffer<{i8::TEXT_SIZE}>;
@@ -37,6 +68,21 @@ pub type BufferSized = Buffer<{isize::TEXT_SIZE}>;
 ///Alias to buffer that can be used to write `128` bit integers
 pub type Buffer128 = Buffer<{i128::TEXT_SIZE}>;
 
+//Size of stack buffer used when value has to be formatted in order to inspect it.
+const SCRATCH_SIZE: usize = 256;
+
+#[inline(always)]
+//Returns last `len` bytes of the buffer, if it is sufficient.
+pub(crate) fn buffer_tail<T>(buffer: &mut [T], len: usize) -> Result<&mut [T], Error> {
+    match buffer.len().checked_sub(len) {
+        Some(offset) => Ok(&mut buffer[offset..]),
+        None => Err(Error::BufferTooSmall {
+            required: len,
+            available: buffer.len(),
+        }),
+    }
+}
+
 ///Describes conversion to string
 ///
 ///This trait is unsafe due to following requirements:
@@ -57,6 +103,8 @@ pub unsafe trait ToStr {
     ///Can panic, if buffer is not sufficient.
     ///Or write only partially
     ///
+    ///Use `try_to_str()` when buffer size is not known to be sufficient.
+    ///
     ///Implementation is allowed to write any part of the buffer.
     ///It is not allowed to read it, unless it was written already.
     ///
@@ -67,6 +115,102 @@ pub unsafe trait ToStr {
     ///UB in release mode is fine if one wants to write efficient code.
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str;
 
+    #[inline(always)]
+    ///Writes textual representation to the uninitialized buffer
+    ///
+    ///Returns `str` stored in the provided `buffer`
+    ///
+    ///Follows the same rules as `to_str()`, but allows to write directly into uninitialized memory.
+    ///
+    ///By default forwards to `to_str()`, relying on implementation to never read unwritten part of the buffer.
+    fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+        self.to_str(unsafe {
+            &mut *(buffer as *mut [MaybeUninit<u8>] as *mut [u8])
+        })
+    }
+
+    #[inline]
+    ///Returns length of textual representation in bytes
+    ///
+    ///By default formats into scratch buffer on stack, when `TEXT_SIZE` does not exceed `256` bytes.
+    ///Otherwise returns `TEXT_SIZE` as upper bound, therefore implementations with bigger size should override it.
+    ///
+    ///Implementations within this crate return exact length, as long as values they wrap do.
+    ///Callers must treat it as upper bound, unless type is known to return exact length.
+    fn text_len(&self) -> usize {
+        if Self::TEXT_SIZE <= SCRATCH_SIZE {
+            let mut scratch = [MaybeUninit::uninit(); SCRATCH_SIZE];
+            self.to_str_uninit(&mut scratch).len()
+        } else {
+            Self::TEXT_SIZE
+        }
+    }
+
+    #[inline(always)]
+    ///Returns whether textual representation is decimal integer
+    ///
+    ///When `true`, `Displayed` applies `+` and `0` flags of `Formatter`, treating leading `-` as sign.
+    ///Text alone cannot tell it, as digits-only text of encodings and identifiers must not be signed.
+    ///
+    ///Only affects padding, therefore safety of implementation never depends on it.
+    ///
+    ///By default returns `false`.
+    fn is_integer(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    ///Writes textual representation to the beginning of the buffer
+    ///
+    ///Returns number of bytes written, with text occupying `buffer[..len]`
+    ///
+    ///Can panic, if buffer is not sufficient.
+    ///
+    ///By default performs `to_str()` and moves written text to the beginning of the buffer.
+    fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+        let text = self.to_str(buffer);
+        let len = text.len();
+        let offset = text.as_ptr() as usize - buffer.as_ptr() as usize;
+        buffer.copy_within(offset..offset + len, 0);
+        len
+    }
+
+    #[inline]
+    ///Writes textual representation to the buffer, if it is sufficient.
+    ///
+    ///Returns `str` stored in the provided `buffer` or error with size required to write the text.
+    ///
+    ///Must never panic.
+    ///
+    ///By default calls `to_str()` when buffer is not below `TEXT_SIZE`.
+    ///Otherwise formats into scratch buffer on stack and copies text to the end of `buffer`.
+    ///If `TEXT_SIZE` exceeds `256` bytes, calls `to_str()` only when buffer is not below `text_len()`,
+    ///in which case implementation must be able to write into such buffer.
+    ///Without override of `text_len()` this requires buffer of `TEXT_SIZE`.
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        if buffer.len() >= Self::TEXT_SIZE {
+            Ok(self.to_str(buffer))
+        } else if Self::TEXT_SIZE <= SCRATCH_SIZE {
+            let mut scratch = [MaybeUninit::uninit(); SCRATCH_SIZE];
+            let text = self.to_str_uninit(&mut scratch);
+            let buffer = buffer_tail(buffer, text.len())?;
+            buffer.copy_from_slice(text.as_bytes());
+            Ok(unsafe {
+                core::str::from_utf8_unchecked(buffer)
+            })
+        } else {
+            let required = self.text_len();
+            if buffer.len() >= required {
+                Ok(self.to_str(buffer))
+            } else {
+                Err(Error::BufferTooSmall {
+                    required,
+                    available: buffer.len(),
+                })
+            }
+        }
+    }
+
     #[inline]
     ///Performs textual conversion by writing to the buffer, if possible.
     ///
@@ -90,6 +234,31 @@ unsafe impl<T: ?Sized + ToStr> ToStr for &T {
     fn to_str<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
         (&**self).to_str(buffer)
     }
+
+    #[inline(always)]
+    fn to_str_uninit<'b>(&self, buffer: &'b mut [MaybeUninit<u8>]) -> &'b str {
+        (&**self).to_str_uninit(buffer)
+    }
+
+    #[inline(always)]
+    fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+        (&**self).to_str_front(buffer)
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        (&**self).text_len()
+    }
+
+    #[inline(always)]
+    fn is_integer(&self) -> bool {
+        (&**self).is_integer()
+    }
+
+    #[inline(always)]
+    fn try_to_str<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b str, Error> {
+        (&**self).try_to_str(buffer)
+    }
 }
 
 unsafe impl<T: ?Sized + ToStr> ToStr for &mut T {
@@ -99,4 +268,29 @@ unsafe impl<T: ?Sized + ToStr> ToStr for &mut T {
     fn to_str<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
         (&**self).to_str(buffer)
     }
+
+    #[inline(always)]
+    fn to_str_uninit<'b>(&self, buffer: &'b mut [MaybeUninit<u8>]) -> &'b str {
+        (&**self).to_str_uninit(buffer)
+    }
+
+    #[inline(always)]
+    fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+        (&**self).to_str_front(buffer)
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        (&**self).text_len()
+    }
+
+    #[inline(always)]
+    fn is_integer(&self) -> bool {
+        (&**self).is_integer()
+    }
+
+    #[inline(always)]
+    fn try_to_str<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b str, Error> {
+        (&**self).try_to_str(buffer)
+    }
 }
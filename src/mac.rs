@@ -0,0 +1,150 @@
+//!Formatting of hardware addresses
+//!
+//!`HwAddr` writes 6 byte MAC (EUI-48) or 8 byte EUI-64 addresses in one of `MacStyle` forms.
+//!
+//!```
+//!use to_str::{Buffer, Case};
+//!use to_str::mac::{MacAddr, MacStyle};
+//!
+//!let addr = MacAddr::new([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+//!assert_eq!(Buffer::<17>::fmt(addr).as_str(), "aa:bb:cc:dd:ee:ff");
+//!assert_eq!(Buffer::<17>::fmt(addr.with_style(MacStyle::Dotted)).as_str(), "aabb.ccdd.eeff");
+//!assert_eq!(Buffer::<17>::fmt(addr.with_style(MacStyle::Hyphen).with_case(Case::Upper)).as_str(), "AA-BB-CC-DD-EE-FF");
+//!```
+
+use crate::{buffer_tail, Case, Error, ToStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///Textual form of hardware address
+pub enum MacStyle {
+    #[default]
+    ///Bytes separated by colon `aa:bb:cc:dd:ee:ff`
+    Colon,
+    ///Bytes separated by hyphen `aa-bb-cc-dd-ee-ff`
+    Hyphen,
+    ///Cisco notation, with pairs of bytes separated by dot `aabb.ccdd.eeff`
+    Dotted,
+    ///No separators `aabbccddeeff`
+    Bare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Hardware address of `N` bytes
+///
+///`ToStr` is implemented for 6 and 8 bytes addresses.
+pub struct HwAddr<const N: usize> {
+    ///Address bytes in transmission order
+    pub bytes: [u8; N],
+    ///Textual form
+    pub style: MacStyle,
+    ///Case of hex digits
+    pub case: Case,
+}
+
+///MAC (EUI-48) address
+pub type MacAddr = HwAddr<6>;
+///EUI-64 address
+pub type Eui64 = HwAddr<8>;
+
+impl<const N: usize> HwAddr<N> {
+    #[inline(always)]
+    ///Creates new instance, written in lowercase `Colon` style
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self {
+            bytes,
+            style: MacStyle::Colon,
+            case: Case::Lower,
+        }
+    }
+
+    #[inline(always)]
+    ///Sets textual form
+    pub const fn with_style(mut self, style: MacStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets case of hex digits
+    pub const fn with_case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    #[inline]
+    const fn len(&self) -> usize {
+        match self.style {
+            MacStyle::Colon | MacStyle::Hyphen => 3 * N - 1,
+            MacStyle::Dotted => 2 * N + N / 2 - 1,
+            MacStyle::Bare => 2 * N,
+        }
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let digits = self.case.hex_digits();
+        let (separator, group) = match self.style {
+            MacStyle::Colon => (b':', 1),
+            MacStyle::Hyphen => (b'-', 1),
+            MacStyle::Dotted => (b'.', 2),
+            MacStyle::Bare => (0, N),
+        };
+
+        let mut cursor = 0;
+        for (idx, byte) in self.bytes.iter().enumerate() {
+            if idx != 0 && idx % group == 0 {
+                buffer[cursor] = separator;
+                cursor += 1;
+            }
+            buffer[cursor] = digits[(byte >> 4) as usize];
+            buffer[cursor + 1] = digits[(byte & 0xf) as usize];
+            cursor += 2;
+        }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for HwAddr<N> {
+    #[inline(always)]
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+macro_rules! impl_to_str {
+    ($($len:literal),+) => {
+        $(
+            unsafe impl ToStr for HwAddr<$len> {
+                const TEXT_SIZE: usize = 3 * $len - 1;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+                    let start = buffer.len() - self.len();
+                    let buffer = &mut buffer[start..];
+                    self.write(buffer);
+                    unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    }
+                }
+
+                #[inline(always)]
+                fn text_len(&self) -> usize {
+                    self.len()
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let buffer = buffer_tail(buffer, self.len())?;
+                    self.write(buffer);
+                    Ok(unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_to_str!(6, 8);
@@ -0,0 +1,359 @@
+//!Formatting of `core::net` addresses
+//!
+//!Output is identical to `Display` of corresponding types:
+//!
+//!- IPv6 addresses are written in RFC 5952 canonical form, with IPv4-mapped addresses as `::ffff:a.b.c.d`;
+//!- IPv6 socket addresses are written as `[ip]:port`, or `[ip%scope]:port` when scope ID is set.
+//!
+//!Network prefixes can be written in CIDR notation via `Cidr` wrapper.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::net::Cidr;
+//!
+//!use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV6};
+//!
+//!assert_eq!(Buffer::<64>::fmt(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0)).as_str(), "[::1]:443");
+//!assert_eq!(Buffer::<32>::fmt(Cidr::<Ipv4Addr>::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()).as_str(), "10.0.0.0/8");
+//!```
+
+use crate::{buffer_tail, Error, ToStr};
+use crate::numeric::{decimal_len, hex_len, write_hex_to_buf, write_u8_to_buf, write_u64_to_buf, HEX_DIGITS};
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+//Must be used within `unsafe` block
+macro_rules! write_byte {
+    ($buffer_ptr:ident[$cursor:ident] = $byte:expr) => {
+        $cursor -= 1;
+        *$buffer_ptr.offset($cursor) = $byte;
+    };
+}
+
+//Writer of the address backwards, ending at `cursor`, returning start of written text.
+//
+//Writer must produce exactly `text_len()` bytes.
+trait Write {
+    fn text_len(&self) -> usize;
+    unsafe fn write(&self, buffer_ptr: *mut u8, cursor: isize) -> isize;
+}
+
+macro_rules! impl_to_str {
+    ($($t:ty: $size:expr;)+) => {
+        $(
+            unsafe impl ToStr for $t {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+                    unsafe {
+                        let offset = Write::write(self, buffer.as_mut_ptr(), buffer.len() as isize) as usize;
+                        core::str::from_utf8_unchecked(&buffer[offset..])
+                    }
+                }
+
+                #[inline(always)]
+                fn text_len(&self) -> usize {
+                    Write::text_len(self)
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let buffer = buffer_tail(buffer, Write::text_len(self))?;
+                    unsafe {
+                        Write::write(self, buffer.as_mut_ptr(), buffer.len() as isize);
+                        Ok(core::str::from_utf8_unchecked(buffer))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl Write for Ipv4Addr {
+    #[inline]
+    fn text_len(&self) -> usize {
+        let [a, b, c, d] = self.octets();
+        3 + decimal_len::u8(a) + decimal_len::u8(b) + decimal_len::u8(c) + decimal_len::u8(d)
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+        let [a, b, c, d] = self.octets();
+        unsafe {
+            cursor = write_u8_to_buf(d, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b'.');
+            cursor = write_u8_to_buf(c, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b'.');
+            cursor = write_u8_to_buf(b, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b'.');
+            write_u8_to_buf(a, buffer_ptr, cursor)
+        }
+    }
+}
+
+//Finds longest run of zero segments as `(start, len)`, preferring the first one.
+//
+//Only runs of at least two segments are compressed as per RFC 5952
+const fn zero_run(segments: &[u16; 8]) -> (usize, usize) {
+    let mut longest = (0, 0);
+    let mut current = (0, 0);
+    let mut idx = 0;
+    while idx < segments.len() {
+        if segments[idx] == 0 {
+            if current.1 == 0 {
+                current.0 = idx;
+            }
+            current.1 += 1;
+            if current.1 > longest.1 {
+                longest = current;
+            }
+        } else {
+            current.1 = 0;
+        }
+        idx += 1;
+    }
+
+    if longest.1 > 1 {
+        longest
+    } else {
+        (0, 0)
+    }
+}
+
+//Length of segments joined with `:`
+fn segments_len(segments: &[u16]) -> usize {
+    segments.iter().map(|segment| hex_len(*segment as usize, 1) + 1).sum::<usize>().saturating_sub(1)
+}
+
+//Writes segments joined with `:`
+unsafe fn write_segments(segments: &[u16], buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+    let mut segments = segments.iter().rev();
+    if let Some(segment) = segments.next() {
+        unsafe {
+            cursor = write_hex_to_buf(*segment as usize, &HEX_DIGITS, 1, buffer_ptr, cursor);
+            for segment in segments {
+                write_byte!(buffer_ptr[cursor] = b':');
+                cursor = write_hex_to_buf(*segment as usize, &HEX_DIGITS, 1, buffer_ptr, cursor);
+            }
+        }
+    }
+    cursor
+}
+
+impl Write for Ipv6Addr {
+    #[inline]
+    fn text_len(&self) -> usize {
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            return "::ffff:".len() + Write::text_len(&ipv4);
+        }
+
+        let segments = self.segments();
+        match zero_run(&segments) {
+            (_, 0) => segments_len(&segments),
+            (start, len) => segments_len(&segments[..start]) + 2 + segments_len(&segments[start + len..]),
+        }
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+        const MAPPED_PREFIX: &[u8] = b"::ffff:";
+
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            unsafe {
+                cursor = Write::write(&ipv4, buffer_ptr, cursor) - MAPPED_PREFIX.len() as isize;
+                core::ptr::copy_nonoverlapping(MAPPED_PREFIX.as_ptr(), buffer_ptr.offset(cursor), MAPPED_PREFIX.len());
+            }
+            return cursor;
+        }
+
+        let segments = self.segments();
+        match zero_run(&segments) {
+            (_, 0) => unsafe {
+                write_segments(&segments, buffer_ptr, cursor)
+            },
+            (start, len) => unsafe {
+                cursor = write_segments(&segments[start + len..], buffer_ptr, cursor);
+                write_byte!(buffer_ptr[cursor] = b':');
+                write_byte!(buffer_ptr[cursor] = b':');
+                write_segments(&segments[..start], buffer_ptr, cursor)
+            },
+        }
+    }
+}
+
+impl Write for IpAddr {
+    #[inline]
+    fn text_len(&self) -> usize {
+        match self {
+            IpAddr::V4(addr) => Write::text_len(addr),
+            IpAddr::V6(addr) => Write::text_len(addr),
+        }
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, cursor: isize) -> isize {
+        unsafe {
+            match self {
+                IpAddr::V4(addr) => Write::write(addr, buffer_ptr, cursor),
+                IpAddr::V6(addr) => Write::write(addr, buffer_ptr, cursor),
+            }
+        }
+    }
+}
+
+impl Write for SocketAddrV4 {
+    #[inline]
+    fn text_len(&self) -> usize {
+        Write::text_len(self.ip()) + 1 + decimal_len::u16(self.port())
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+        unsafe {
+            cursor = write_u64_to_buf(self.port() as u64, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b':');
+            Write::write(self.ip(), buffer_ptr, cursor)
+        }
+    }
+}
+
+impl Write for SocketAddrV6 {
+    #[inline]
+    fn text_len(&self) -> usize {
+        let scope_len = match self.scope_id() {
+            0 => 0,
+            scope_id => 1 + decimal_len::u32(scope_id),
+        };
+        1 + Write::text_len(self.ip()) + scope_len + 2 + decimal_len::u16(self.port())
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+        unsafe {
+            cursor = write_u64_to_buf(self.port() as u64, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b':');
+            write_byte!(buffer_ptr[cursor] = b']');
+            if self.scope_id() != 0 {
+                cursor = write_u64_to_buf(self.scope_id() as u64, buffer_ptr, cursor);
+                write_byte!(buffer_ptr[cursor] = b'%');
+            }
+            cursor = Write::write(self.ip(), buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b'[');
+        }
+        cursor
+    }
+}
+
+impl Write for SocketAddr {
+    #[inline]
+    fn text_len(&self) -> usize {
+        match self {
+            SocketAddr::V4(addr) => Write::text_len(addr),
+            SocketAddr::V6(addr) => Write::text_len(addr),
+        }
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, cursor: isize) -> isize {
+        unsafe {
+            match self {
+                SocketAddr::V4(addr) => Write::write(addr, buffer_ptr, cursor),
+                SocketAddr::V6(addr) => Write::write(addr, buffer_ptr, cursor),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Network prefix in CIDR notation `addr/prefix`
+///
+///Address is written as is, without masking bits past prefix.
+pub struct Cidr<A> {
+    addr: A,
+    prefix: u8,
+}
+
+impl<A> Cidr<A> {
+    #[inline(always)]
+    ///Returns address
+    pub const fn addr(&self) -> &A {
+        &self.addr
+    }
+
+    #[inline(always)]
+    ///Returns prefix length in bits
+    pub const fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+impl Cidr<Ipv4Addr> {
+    #[inline]
+    ///Creates new instance, returning `None` if `prefix` exceeds 32 bits
+    pub const fn new(addr: Ipv4Addr, prefix: u8) -> Option<Self> {
+        if prefix > 32 {
+            None
+        } else {
+            Some(Self { addr, prefix })
+        }
+    }
+}
+
+impl Cidr<Ipv6Addr> {
+    #[inline]
+    ///Creates new instance, returning `None` if `prefix` exceeds 128 bits
+    pub const fn new(addr: Ipv6Addr, prefix: u8) -> Option<Self> {
+        if prefix > 128 {
+            None
+        } else {
+            Some(Self { addr, prefix })
+        }
+    }
+}
+
+impl Cidr<IpAddr> {
+    #[inline]
+    ///Creates new instance, returning `None` if `prefix` exceeds bit length of the address
+    pub const fn new(addr: IpAddr, prefix: u8) -> Option<Self> {
+        let max = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix > max {
+            None
+        } else {
+            Some(Self { addr, prefix })
+        }
+    }
+}
+
+impl<A: Write> Write for Cidr<A> {
+    #[inline]
+    fn text_len(&self) -> usize {
+        Write::text_len(&self.addr) + 1 + decimal_len::u8(self.prefix)
+    }
+
+    #[inline]
+    unsafe fn write(&self, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+        unsafe {
+            cursor = write_u8_to_buf(self.prefix, buffer_ptr, cursor);
+            write_byte!(buffer_ptr[cursor] = b'/');
+            Write::write(&self.addr, buffer_ptr, cursor)
+        }
+    }
+}
+
+impl_to_str!(
+    Ipv4Addr: "255.255.255.255".len();
+    Ipv6Addr: "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".len();
+    IpAddr: <Ipv6Addr as ToStr>::TEXT_SIZE;
+    SocketAddrV4: <Ipv4Addr as ToStr>::TEXT_SIZE + ":65535".len();
+    SocketAddrV6: "[]".len() + <Ipv6Addr as ToStr>::TEXT_SIZE + "%4294967295".len() + ":65535".len();
+    SocketAddr: <SocketAddrV6 as ToStr>::TEXT_SIZE;
+    Cidr<Ipv4Addr>: <Ipv4Addr as ToStr>::TEXT_SIZE + "/32".len();
+    Cidr<Ipv6Addr>: <Ipv6Addr as ToStr>::TEXT_SIZE + "/128".len();
+    Cidr<IpAddr>: <Ipv6Addr as ToStr>::TEXT_SIZE + "/128".len();
+);
@@ -1,6 +1,20 @@
-use crate::ToStr;
+//!Numeric formatting routines
+//!
+//!Provides `const fn` formatting of primitive integers directly into uninitialized memory.
+//!
+//!```
+//!use core::mem::MaybeUninit;
+//!use to_str::numeric;
+//!
+//!let mut buffer = [MaybeUninit::<u8>::uninit(); 20];
+//!assert_eq!(numeric::unsigned::u64(u64::MAX, &mut buffer), "18446744073709551615");
+//!assert_eq!(numeric::signed::i8(i8::MIN, &mut buffer), "-128");
+//!```
+
+use crate::{buffer_tail, Error, ToStr};
 
 use core::{num, ptr};
+use core::mem::MaybeUninit;
 
 //num % 100 * 2 + 1 at most will be 200, therefore DIGITS contains this much.
 static DEC_DIGITS: &[u8; 200] = b"0001020304050607080910111213141516171819\
@@ -8,9 +22,53 @@ static DEC_DIGITS: &[u8; 200] = b"0001020304050607080910111213141516171819\
                                   4041424344454647484950515253545556575859\
                                   6061626364656667686970717273747576777879\
                                   8081828384858687888990919293949596979899";
-static HEX_DIGITS: [u8; 16] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f'];
+pub(crate) static HEX_DIGITS: [u8; 16] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f'];
+pub(crate) static HEX_DIGITS_UPPER: [u8; 16] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F'];
 const PTR_PREFIX: [u8; 2] = [b'0', b'x'];
 
+const fn hex_pairs(digits: &[u8; 16]) -> [u8; 512] {
+    let mut table = [0u8; 512];
+    let mut idx = 0;
+    while idx < 256 {
+        table[idx * 2] = digits[idx >> 4];
+        table[idx * 2 + 1] = digits[idx & 0xf];
+        idx += 1;
+    }
+    table
+}
+
+//Pairs of hex digits for every byte value, similar to DEC_DIGITS
+static HEX_PAIRS: [u8; 512] = hex_pairs(&HEX_DIGITS);
+static HEX_PAIRS_UPPER: [u8; 512] = hex_pairs(&HEX_DIGITS_UPPER);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///Case of hexadecimal digits
+pub enum Case {
+    #[default]
+    ///Lowercase digits `a-f`
+    Lower,
+    ///Uppercase digits `A-F`
+    Upper,
+}
+
+impl Case {
+    #[inline(always)]
+    pub(crate) const fn hex_digits(self) -> &'static [u8; 16] {
+        match self {
+            Case::Lower => &HEX_DIGITS,
+            Case::Upper => &HEX_DIGITS_UPPER,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn hex_pairs(self) -> &'static [u8; 512] {
+        match self {
+            Case::Lower => &HEX_PAIRS,
+            Case::Upper => &HEX_PAIRS_UPPER,
+        }
+    }
+}
+
 const fn size_of_val<T>(_: &T) -> usize {
     core::mem::size_of::<T>()
 }
@@ -181,10 +239,22 @@ pub(crate) const unsafe fn write_u128_to_buf(mut num: u128, buffer_ptr: *mut u8,
     }
 }
 
-const unsafe fn write_hex_to_buf(mut num: usize, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+//Number of hex digits required to write `num`, but no less than `min_len`
+pub(crate) const fn hex_len(num: usize, min_len: usize) -> usize {
+    let len = (usize::BITS - (num | 1).leading_zeros()).div_ceil(4) as usize;
+    if len > min_len {
+        len
+    } else {
+        min_len
+    }
+}
+
+//Writes `num` using `digits`, padding with zeroes up to `min_len` digits
+pub(crate) const unsafe fn write_hex_to_buf(mut num: usize, digits: &[u8; 16], min_len: usize, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
     const BASE: usize = 4;
     const BASE_DIGIT: usize = (1 << BASE) - 1;
-    let digits_ptr = HEX_DIGITS.as_ptr();
+    let digits_ptr = digits.as_ptr();
+    let end = cursor;
 
     loop {
         let digit = num & BASE_DIGIT;
@@ -199,6 +269,55 @@ const unsafe fn write_hex_to_buf(mut num: usize, buffer_ptr: *mut u8, mut cursor
         }
     }
 
+    while ((end - cursor) as usize) < min_len {
+        cursor -= 1;
+        unsafe {
+            ptr::write(buffer_ptr.offset(cursor), b'0');
+        }
+    }
+
+    cursor
+}
+
+//Number of hex digits required to write `num`, but no less than `min_len`
+pub(crate) const fn hex_len_u32(num: u32, min_len: usize) -> usize {
+    let len = (u32::BITS - (num | 1).leading_zeros()).div_ceil(4) as usize;
+    if len > min_len {
+        len
+    } else {
+        min_len
+    }
+}
+
+//Writes `num` using `digits`, padding with zeroes up to `min_len` digits
+//
+//Unlike `write_hex_to_buf` it doesn't depend on width of `usize`, therefore suitable for code points.
+pub(crate) const unsafe fn write_hex_u32_to_buf(mut num: u32, digits: &[u8; 16], min_len: usize, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
+    const BASE: u32 = 4;
+    const BASE_DIGIT: u32 = (1 << BASE) - 1;
+    let digits_ptr = digits.as_ptr();
+    let end = cursor;
+
+    loop {
+        let digit = num & BASE_DIGIT;
+        cursor -= 1;
+        unsafe {
+            ptr::write(buffer_ptr.offset(cursor), *digits_ptr.add(digit as usize));
+        }
+        num >>= BASE;
+
+        if num == 0 {
+            break;
+        }
+    }
+
+    while ((end - cursor) as usize) < min_len {
+        cursor -= 1;
+        unsafe {
+            ptr::write(buffer_ptr.offset(cursor), b'0');
+        }
+    }
+
     cursor
 }
 
@@ -206,7 +325,7 @@ const unsafe fn write_hex_to_buf(mut num: usize, buffer_ptr: *mut u8, mut cursor
 pub(crate) const unsafe fn write_ptr_to_buf(num: usize, buffer_ptr: *mut u8, mut cursor: isize) -> isize {
     const PTR_PREFIX_SIZE: usize = size_of_val(&PTR_PREFIX);
     cursor = unsafe {
-        write_hex_to_buf(num, buffer_ptr, cursor)
+        write_hex_to_buf(num, &HEX_DIGITS, 1, buffer_ptr, cursor)
     };
     cursor -= PTR_PREFIX_SIZE as isize;
 
@@ -217,10 +336,126 @@ pub(crate) const unsafe fn write_ptr_to_buf(num: usize, buffer_ptr: *mut u8, mut
     cursor
 }
 
+///Exact length of decimal representation
+///
+///All functions are branchless and return number of bytes required to write value, including sign.
+pub mod decimal_len {
+    //Powers of 10 up to the greatest one fitting into u128.
+    static POW10: [u128; 39] = {
+        let mut table = [1u128; 39];
+        let mut idx = 1;
+        while idx < table.len() {
+            table[idx] = table[idx - 1] * 10;
+            idx += 1;
+        }
+        table
+    };
+
+    #[inline(always)]
+    //Estimates log10 from log2 as `(log2 + 1) * 1233 >> 12` and then corrects it by single comparison.
+    const fn estimate(bits: u32) -> usize {
+        (bits as usize * 1233) >> 12
+    }
+
+    #[inline]
+    ///Returns length of `u64` in decimal
+    pub const fn u64(num: u64) -> usize {
+        let num = num | 1;
+        let log10 = estimate(u64::BITS - num.leading_zeros());
+        log10 + (num as u128 >= POW10[log10]) as usize
+    }
+
+    #[inline]
+    ///Returns length of `u128` in decimal
+    pub const fn u128(num: u128) -> usize {
+        let num = num | 1;
+        let log10 = estimate(u128::BITS - num.leading_zeros());
+        log10 + (num >= POW10[log10]) as usize
+    }
+
+    #[inline(always)]
+    ///Returns length of `u8` in decimal
+    pub const fn u8(num: u8) -> usize {
+        u64(num as _)
+    }
+
+    #[inline(always)]
+    ///Returns length of `u16` in decimal
+    pub const fn u16(num: u16) -> usize {
+        u64(num as _)
+    }
+
+    #[inline(always)]
+    ///Returns length of `u32` in decimal
+    pub const fn u32(num: u32) -> usize {
+        u64(num as _)
+    }
+
+    #[inline(always)]
+    ///Returns length of `usize` in decimal
+    pub const fn usize(num: usize) -> usize {
+        u64(num as _)
+    }
+
+    #[inline(always)]
+    ///Returns length of `i8` in decimal
+    pub const fn i8(num: i8) -> usize {
+        num.is_negative() as usize + u8(num.unsigned_abs())
+    }
+
+    #[inline(always)]
+    ///Returns length of `i16` in decimal
+    pub const fn i16(num: i16) -> usize {
+        num.is_negative() as usize + u16(num.unsigned_abs())
+    }
+
+    #[inline(always)]
+    ///Returns length of `i32` in decimal
+    pub const fn i32(num: i32) -> usize {
+        num.is_negative() as usize + u32(num.unsigned_abs())
+    }
+
+    #[inline(always)]
+    ///Returns length of `i64` in decimal
+    pub const fn i64(num: i64) -> usize {
+        num.is_negative() as usize + u64(num.unsigned_abs())
+    }
+
+    #[inline(always)]
+    ///Returns length of `i128` in decimal
+    pub const fn i128(num: i128) -> usize {
+        num.is_negative() as usize + u128(num.unsigned_abs())
+    }
+
+    #[inline(always)]
+    ///Returns length of `isize` in decimal
+    pub const fn isize(num: isize) -> usize {
+        num.is_negative() as usize + usize(num.unsigned_abs())
+    }
+}
+
 macro_rules! impl_unsigned {
-    ($t:ident: $max:expr; $conv:ident($($cv_t:tt)*)) => {
+    ($t:ident, $unchecked:ident: $max:expr; $conv:ident($($cv_t:tt)*)) => {
         #[inline]
-        pub(crate) const fn $t(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+        #[doc = concat!("Writes `", stringify!($t), "` into the end of `buffer`, returning written text.")]
+        ///
+        ///# Panics
+        ///
+        ///If `buffer` is shorter than `TEXT_SIZE`
+        pub const fn $t(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+            assert!(buffer.len() >= <$t as crate::ToStr>::TEXT_SIZE, "Buffer should be sufficient");
+            unsafe {
+                $unchecked(num, buffer)
+            }
+        }
+
+        #[inline]
+        #[doc = concat!("Writes `", stringify!($t), "` into the end of `buffer`, returning written text, without checking size of `buffer`.")]
+        ///
+        ///# Safety
+        ///
+        ///`buffer` must not be shorter than `TEXT_SIZE`
+        pub const unsafe fn $unchecked(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
             debug_assert!(buffer.len() >= <$t as crate::ToStr>::TEXT_SIZE);
             unsafe {
                 let offset = super::$conv(num $($cv_t)*, buffer.as_mut_ptr() as *mut u8, buffer.len() as isize);
@@ -234,23 +469,78 @@ macro_rules! impl_unsigned {
 
             #[inline(always)]
             fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
-                let buffer = unsafe {
-                    core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer)
-                };
-                $t(*self, buffer)
+                unsafe {
+                    $unchecked(*self, core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer))
+                }
+            }
+
+            #[inline(always)]
+            fn to_str_uninit<'a>(&self, buffer: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a str {
+                unsafe {
+                    $unchecked(*self, buffer)
+                }
+            }
+
+            #[inline(always)]
+            fn text_len(&self) -> usize {
+                super::decimal_len::$t(*self)
+            }
+
+            #[inline(always)]
+            fn is_integer(&self) -> bool {
+                true
+            }
+
+            #[inline]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, crate::Error> {
+                let buffer = crate::buffer_tail(buffer, super::decimal_len::$t(*self))?;
+                unsafe {
+                    super::$conv(*self $($cv_t)*, buffer.as_mut_ptr(), buffer.len() as isize);
+                    Ok(core::str::from_utf8_unchecked(buffer))
+                }
+            }
+
+            #[inline]
+            fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+                let len = super::decimal_len::$t(*self);
+                assert!(buffer.len() >= len, "Buffer should be sufficient");
+                unsafe {
+                    super::$conv(*self $($cv_t)*, buffer.as_mut_ptr(), len as isize);
+                }
+                len
             }
         }
     }
 }
 
+///Unsigned integers formatting
 pub mod unsigned {
-    impl_unsigned!(u8: 3; write_u8_to_buf(as u8));
-    impl_unsigned!(u16: 5; write_u64_to_buf(as u64));
-    impl_unsigned!(u32: 10; write_u64_to_buf(as u64));
-    impl_unsigned!(u64: 20; write_u64_to_buf(as u64));
-    impl_unsigned!(u128: 39; write_u128_to_buf(as u128));
+    impl_unsigned!(u8, u8_unchecked: 3; write_u8_to_buf(as u8));
+    impl_unsigned!(u16, u16_unchecked: 5; write_u64_to_buf(as u64));
+    impl_unsigned!(u32, u32_unchecked: 10; write_u64_to_buf(as u64));
+    impl_unsigned!(u64, u64_unchecked: 20; write_u64_to_buf(as u64));
+    impl_unsigned!(u128, u128_unchecked: 39; write_u128_to_buf(as u128));
 
-    pub(crate) const fn usize(num: usize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+    #[inline]
+    ///Writes `usize` into the end of `buffer`, returning written text.
+    ///
+    ///# Panics
+    ///
+    ///If `buffer` is shorter than `TEXT_SIZE`
+    pub const fn usize(num: usize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+        assert!(buffer.len() >= <usize as crate::ToStr>::TEXT_SIZE, "Buffer should be sufficient");
+        unsafe {
+            usize_unchecked(num, buffer)
+        }
+    }
+
+    #[inline]
+    ///Writes `usize` into the end of `buffer`, returning written text, without checking size of `buffer`.
+    ///
+    ///# Safety
+    ///
+    ///`buffer` must not be shorter than `TEXT_SIZE`
+    pub const unsafe fn usize_unchecked(num: usize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
         debug_assert!(buffer.len() >= <usize as crate::ToStr>::TEXT_SIZE);
         unsafe {
             let offset = super::write_u64_to_buf(num as _, buffer.as_mut_ptr() as *mut u8, buffer.len() as isize);
@@ -270,17 +560,70 @@ unsafe impl ToStr for usize {
 
     #[inline]
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
-        let buffer = unsafe {
-            core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer)
-        };
-        unsigned::usize(*self, buffer)
+        unsafe {
+            unsigned::usize_unchecked(*self, core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer))
+        }
+    }
+
+    #[inline(always)]
+    fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+        unsafe {
+            unsigned::usize_unchecked(*self, buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        decimal_len::usize(*self)
+    }
+
+    #[inline(always)]
+    fn is_integer(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, decimal_len::usize(*self))?;
+        unsafe {
+            write_u64_to_buf(*self as _, buffer.as_mut_ptr(), buffer.len() as isize);
+            Ok(core::str::from_utf8_unchecked(buffer))
+        }
+    }
+
+    #[inline]
+    fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+        let len = decimal_len::usize(*self);
+        assert!(buffer.len() >= len, "Buffer should be sufficient");
+        unsafe {
+            write_u64_to_buf(*self as _, buffer.as_mut_ptr(), len as isize);
+        }
+        len
     }
 }
 
 macro_rules! impl_signed {
-    ($t:ident as $st:ident where $conv:ident as $cv_t:ty) => {
+    ($t:ident, $unchecked:ident as $st:ident, $st_unchecked:ident where $conv:ident as $cv_t:ty) => {
+        #[inline]
+        #[doc = concat!("Writes `", stringify!($t), "` into the end of `buffer`, returning written text.")]
+        ///
+        ///# Panics
+        ///
+        ///If `buffer` is shorter than `TEXT_SIZE`
+        pub const fn $t(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+            assert!(buffer.len() >= <$t as crate::ToStr>::TEXT_SIZE, "Buffer should be sufficient");
+            unsafe {
+                $unchecked(num, buffer)
+            }
+        }
+
         #[inline]
-        pub(crate) const fn $t(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+        #[doc = concat!("Writes `", stringify!($t), "` into the end of `buffer`, returning written text, without checking size of `buffer`.")]
+        ///
+        ///# Safety
+        ///
+        ///`buffer` must not be shorter than `TEXT_SIZE`
+        pub const unsafe fn $unchecked(num: $t, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
             if num.is_negative() {
                 debug_assert!(buffer.len() >= <$t as crate::ToStr>::TEXT_SIZE);
 
@@ -293,7 +636,9 @@ macro_rules! impl_signed {
                 }
 
             } else {
-                crate::numeric::unsigned::$st(num as $st, buffer)
+                unsafe {
+                    crate::numeric::unsigned::$st_unchecked(num as $st, buffer)
+                }
             }
         }
 
@@ -302,24 +647,86 @@ macro_rules! impl_signed {
 
             #[inline(always)]
             fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
-                let buffer = unsafe {
-                    core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer)
-                };
-                $t(*self, buffer)
+                unsafe {
+                    $unchecked(*self, core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer))
+                }
+            }
+
+            #[inline(always)]
+            fn to_str_uninit<'a>(&self, buffer: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a str {
+                unsafe {
+                    $unchecked(*self, buffer)
+                }
+            }
+
+            #[inline(always)]
+            fn text_len(&self) -> usize {
+                super::decimal_len::$t(*self)
+            }
+
+            #[inline(always)]
+            fn is_integer(&self) -> bool {
+                true
+            }
+
+            #[inline]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, crate::Error> {
+                let buffer = crate::buffer_tail(buffer, super::decimal_len::$t(*self))?;
+                unsafe {
+                    super::$conv(self.unsigned_abs() as $cv_t, buffer.as_mut_ptr(), buffer.len() as isize);
+                }
+                if self.is_negative() {
+                    buffer[0] = b'-';
+                }
+                Ok(unsafe {
+                    core::str::from_utf8_unchecked(buffer)
+                })
+            }
+
+            #[inline]
+            fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+                let len = super::decimal_len::$t(*self);
+                assert!(buffer.len() >= len, "Buffer should be sufficient");
+                unsafe {
+                    super::$conv(self.unsigned_abs() as $cv_t, buffer.as_mut_ptr(), len as isize);
+                }
+                if self.is_negative() {
+                    buffer[0] = b'-';
+                }
+                len
             }
         }
     }
 }
 
+///Signed integers formatting
 pub mod signed {
-    impl_signed!(i8 as u8 where write_u8_to_buf as u8);
-    impl_signed!(i16 as u16 where write_u64_to_buf as u64);
-    impl_signed!(i32 as u32 where write_u64_to_buf as u64);
-    impl_signed!(i64 as u64 where write_u64_to_buf as u64);
-    impl_signed!(i128 as u128 where write_u128_to_buf as u128);
+    impl_signed!(i8, i8_unchecked as u8, u8_unchecked where write_u8_to_buf as u8);
+    impl_signed!(i16, i16_unchecked as u16, u16_unchecked where write_u64_to_buf as u64);
+    impl_signed!(i32, i32_unchecked as u32, u32_unchecked where write_u64_to_buf as u64);
+    impl_signed!(i64, i64_unchecked as u64, u64_unchecked where write_u64_to_buf as u64);
+    impl_signed!(i128, i128_unchecked as u128, u128_unchecked where write_u128_to_buf as u128);
 
     #[inline]
-    pub(crate) const fn isize(num: isize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+    ///Writes `isize` into the end of `buffer`, returning written text.
+    ///
+    ///# Panics
+    ///
+    ///If `buffer` is shorter than `TEXT_SIZE`
+    pub const fn isize(num: isize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
+        assert!(buffer.len() >= <isize as crate::ToStr>::TEXT_SIZE, "Buffer should be sufficient");
+        unsafe {
+            isize_unchecked(num, buffer)
+        }
+    }
+
+    #[inline]
+    ///Writes `isize` into the end of `buffer`, returning written text, without checking size of `buffer`.
+    ///
+    ///# Safety
+    ///
+    ///`buffer` must not be shorter than `TEXT_SIZE`
+    pub const unsafe fn isize_unchecked(num: isize, buffer: &'_ mut [core::mem::MaybeUninit<u8>]) -> &'_ str {
         if num.is_negative() {
             debug_assert!(buffer.len() >= <isize as crate::ToStr>::TEXT_SIZE);
 
@@ -337,7 +744,9 @@ pub mod signed {
                 core::str::from_utf8_unchecked(slice)
             }
         } else {
-            super::unsigned::usize(num as _, buffer)
+            unsafe {
+                super::unsigned::usize_unchecked(num as _, buffer)
+            }
         }
     }
 }
@@ -353,10 +762,53 @@ unsafe impl ToStr for isize {
     #[inline(always)]
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
 
-        let buffer = unsafe {
-            core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer)
-        };
-        signed::isize(*self, buffer)
+        unsafe {
+            signed::isize_unchecked(*self, core::mem::transmute::<&'a mut [u8], &'a mut [core::mem::MaybeUninit<u8>]>(buffer))
+        }
+    }
+
+    #[inline(always)]
+    fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+        unsafe {
+            signed::isize_unchecked(*self, buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        decimal_len::isize(*self)
+    }
+
+    #[inline(always)]
+    fn is_integer(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, decimal_len::isize(*self))?;
+        unsafe {
+            write_u64_to_buf(self.unsigned_abs() as _, buffer.as_mut_ptr(), buffer.len() as isize);
+        }
+        if self.is_negative() {
+            buffer[0] = b'-';
+        }
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+
+    #[inline]
+    fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+        let len = decimal_len::isize(*self);
+        assert!(buffer.len() >= len, "Buffer should be sufficient");
+        unsafe {
+            write_u64_to_buf(self.unsigned_abs() as _, buffer.as_mut_ptr(), len as isize);
+        }
+        if self.is_negative() {
+            buffer[0] = b'-';
+        }
+        len
     }
 }
 
@@ -372,6 +824,20 @@ unsafe impl<T> ToStr for *const T {
             core::str::from_utf8_unchecked(&buffer[offset..])
         }
     }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        size_of_val(&PTR_PREFIX) + hex_len(*self as usize, 1)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.text_len())?;
+        unsafe {
+            write_ptr_to_buf(*self as usize, buffer.as_mut_ptr(), buffer.len() as isize);
+            Ok(core::str::from_utf8_unchecked(buffer))
+        }
+    }
 }
 
 unsafe impl<T> ToStr for *mut T {
@@ -381,6 +847,16 @@ unsafe impl<T> ToStr for *mut T {
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
         (*self as *const T).to_str(buffer)
     }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        (*self as *const T).text_len()
+    }
+
+    #[inline(always)]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        (*self as *const T).try_to_str(buffer)
+    }
 }
 
 unsafe impl<T> ToStr for core::sync::atomic::AtomicPtr<T> {
@@ -390,6 +866,16 @@ unsafe impl<T> ToStr for core::sync::atomic::AtomicPtr<T> {
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
         self.load(core::sync::atomic::Ordering::Acquire).to_str(buffer)
     }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.load(core::sync::atomic::Ordering::Acquire).text_len()
+    }
+
+    #[inline(always)]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        self.load(core::sync::atomic::Ordering::Acquire).try_to_str(buffer)
+    }
 }
 
 unsafe impl<T> ToStr for ptr::NonNull<T> {
@@ -399,6 +885,16 @@ unsafe impl<T> ToStr for ptr::NonNull<T> {
     fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
         self.as_ptr().to_str(buffer)
     }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.as_ptr().text_len()
+    }
+
+    #[inline(always)]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        self.as_ptr().try_to_str(buffer)
+    }
 }
 
 macro_rules! impl_non_zero_repr {
@@ -414,6 +910,31 @@ macro_rules! impl_non_zero_repr {
             fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
                 ToStr::to_str(&(*self).get(), buffer)
             }
+
+            #[inline(always)]
+            fn to_str_uninit<'a>(&self, buffer: &'a mut [MaybeUninit<u8>]) -> &'a str {
+                ToStr::to_str_uninit(&(*self).get(), buffer)
+            }
+
+            #[inline(always)]
+            fn to_str_front(&self, buffer: &mut [u8]) -> usize {
+                ToStr::to_str_front(&(*self).get(), buffer)
+            }
+
+            #[inline(always)]
+            fn text_len(&self) -> usize {
+                ToStr::text_len(&(*self).get())
+            }
+
+            #[inline(always)]
+            fn is_integer(&self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                ToStr::try_to_str(&(*self).get(), buffer)
+            }
         }
         )*
     }
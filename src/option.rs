@@ -0,0 +1,138 @@
+//!Formatting of optional values
+//!
+//!`Option` is written as its value, or `None` when absent.
+//!Text of absent value can be selected via `OptionOr` wrapper.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::option::{Dash, OptionOr};
+//!
+//!assert_eq!(Buffer::<8>::fmt(Some(5u8)).as_str(), "5");
+//!assert_eq!(Buffer::<8>::fmt(None::<u8>).as_str(), "None");
+//!assert_eq!(Buffer::<8>::fmt(OptionOr::<u8, Dash>::new(None)).as_str(), "-");
+//!```
+
+use crate::{Error, ToStr};
+use crate::parts::{max_size, unwrap_text, write_tail};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+///Text to write in place of absent value
+pub trait Placeholder {
+    ///Text of absent value
+    const TEXT: &'static str;
+}
+
+#[derive(Debug, Clone, Copy)]
+///Placeholder `None`
+pub struct NoneText;
+
+impl Placeholder for NoneText {
+    const TEXT: &'static str = "None";
+}
+
+#[derive(Debug, Clone, Copy)]
+///Empty placeholder
+pub struct Empty;
+
+impl Placeholder for Empty {
+    const TEXT: &'static str = "";
+}
+
+#[derive(Debug, Clone, Copy)]
+///Placeholder `-`
+pub struct Dash;
+
+impl Placeholder for Dash {
+    const TEXT: &'static str = "-";
+}
+
+#[inline]
+fn text_len<T: ToStr, P: Placeholder>(value: &Option<T>) -> usize {
+    match value {
+        Some(value) => value.text_len(),
+        None => P::TEXT.len(),
+    }
+}
+
+#[inline]
+fn try_to_str<'a, T: ToStr, P: Placeholder, V: ToStr>(this: &V, value: &Option<T>, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+    match value {
+        Some(value) => value.try_to_str(buffer),
+        None => write_tail(this, buffer, |tail| tail.put_str(P::TEXT)),
+    }
+}
+
+#[repr(transparent)]
+///Wrapper to format `Option` with specified `Placeholder` for `None`
+pub struct OptionOr<T, P> {
+    ///Optional value
+    pub value: Option<T>,
+    _placeholder: PhantomData<P>,
+}
+
+impl<T, P> OptionOr<T, P> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(value: Option<T>) -> Self {
+        Self {
+            value,
+            _placeholder: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, P> Clone for OptionOr<T, P> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, P> Copy for OptionOr<T, P> {}
+
+impl<T: fmt::Debug, P> fmt::Debug for OptionOr<T, P> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, fmt)
+    }
+}
+
+unsafe impl<T: ToStr, P: Placeholder> ToStr for OptionOr<T, P> {
+    const TEXT_SIZE: usize = max_size(T::TEXT_SIZE, P::TEXT.len());
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        text_len::<T, P>(&self.value)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        try_to_str::<T, P, Self>(self, &self.value, buffer)
+    }
+}
+
+unsafe impl<T: ToStr> ToStr for Option<T> {
+    const TEXT_SIZE: usize = max_size(T::TEXT_SIZE, NoneText::TEXT.len());
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        text_len::<T, NoneText>(self)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        try_to_str::<T, NoneText, Self>(self, self, buffer)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Error, ToStr};
+
+//Writer of text parts from the end of the buffer.
+//
+//All parts must be valid UTF-8, therefore written text is always valid.
+pub struct Tail<'a> {
+    buffer: &'a mut [u8],
+    cursor: usize,
+}
+
+impl<'a> Tail<'a> {
+    #[inline(always)]
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            cursor: buffer.len(),
+            buffer,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn put_str(&mut self, text: &str) -> Option<()> {
+        let start = self.cursor.checked_sub(text.len())?;
+        self.buffer[start..self.cursor].copy_from_slice(text.as_bytes());
+        self.cursor = start;
+        Some(())
+    }
+
+    #[inline]
+    pub(crate) fn put<T: ?Sized + ToStr>(&mut self, value: &T) -> Option<()> {
+        let head = &mut self.buffer[..self.cursor];
+        let text = value.try_to_str(head).ok()?;
+        let len = text.len();
+        let start = text.as_ptr() as usize - head.as_ptr() as usize;
+        //Third party implementation may not write to the very end
+        if start + len != self.cursor {
+            head.copy_within(start..start + len, self.cursor - len);
+        }
+        self.cursor -= len;
+        Some(())
+    }
+
+    #[inline(always)]
+    pub(crate) fn finish(self) -> &'a str {
+        unsafe {
+            core::str::from_utf8_unchecked(&self.buffer[self.cursor..])
+        }
+    }
+}
+
+#[inline]
+//Writes `value` via `write`, reporting `text_len()` of the `value` on failure.
+pub(crate) fn write_tail<'a, T: ?Sized + ToStr>(value: &T, buffer: &'a mut [u8], write: impl FnOnce(&mut Tail<'a>) -> Option<()>) -> Result<&'a str, Error> {
+    let available = buffer.len();
+    let mut tail = Tail::new(buffer);
+    match write(&mut tail) {
+        Some(()) => Ok(tail.finish()),
+        None => Err(Error::BufferTooSmall {
+            required: value.text_len(),
+            available,
+        }),
+    }
+}
+
+#[inline(always)]
+//Unwraps result of `try_to_str()` for `to_str()` implementations.
+pub(crate) fn unwrap_text(result: Result<&str, Error>) -> &str {
+    match result {
+        Ok(text) => text,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+#[inline(always)]
+//Returns maximum of two sizes in const context.
+pub(crate) const fn max_size(left: usize, right: usize) -> usize {
+    if left > right {
+        left
+    } else {
+        right
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{buffer_tail, Error, ToStr};
+use crate::parts::unwrap_text;
+
+use core::{cmp, num};
+
+macro_rules! impl_static_text {
+    ($($t:ty: $size:expr => |$this:ident| $text:expr;)+) => {
+        $(
+            unsafe impl ToStr for $t {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    unwrap_text(self.try_to_str(buffer))
+                }
+
+                #[inline]
+                fn text_len(&self) -> usize {
+                    let $this = self;
+                    $text.len()
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let $this = self;
+                    let text: &'static str = $text;
+                    let buffer = buffer_tail(buffer, text.len())?;
+                    buffer.copy_from_slice(text.as_bytes());
+                    Ok(unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_static_text!(
+    bool: "false".len() => |this| if *this { "true" } else { "false" };
+    cmp::Ordering: "Greater".len() => |this| match this {
+        cmp::Ordering::Less => "Less",
+        cmp::Ordering::Equal => "Equal",
+        cmp::Ordering::Greater => "Greater",
+    };
+    num::FpCategory: "Subnormal".len() => |this| match this {
+        num::FpCategory::Nan => "Nan",
+        num::FpCategory::Infinite => "Infinite",
+        num::FpCategory::Zero => "Zero",
+        num::FpCategory::Subnormal => "Subnormal",
+        num::FpCategory::Normal => "Normal",
+    };
+);
+
+unsafe impl ToStr for char {
+    const TEXT_SIZE: usize = 4;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len_utf8()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len_utf8())?;
+        Ok(self.encode_utf8(buffer))
+    }
+}
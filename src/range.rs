@@ -0,0 +1,80 @@
+use crate::{Error, ToStr};
+use crate::parts::{unwrap_text, write_tail};
+
+use core::ops;
+
+const EXCLUSIVE: &str = "..";
+const INCLUSIVE: &str = "..=";
+
+#[inline]
+fn text_len<T: ToStr>(start: Option<&T>, op: &str, end: Option<&T>) -> usize {
+    start.map_or(0, ToStr::text_len) + op.len() + end.map_or(0, ToStr::text_len)
+}
+
+#[inline]
+fn try_to_str<'a, R: ToStr, T: ToStr>(range: &R, start: Option<&T>, op: &str, end: Option<&T>, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+    write_tail(range, buffer, |tail| {
+        if let Some(end) = end {
+            tail.put(end)?;
+        }
+        tail.put_str(op)?;
+        if let Some(start) = start {
+            tail.put(start)?;
+        }
+        Some(())
+    })
+}
+
+macro_rules! impl_range {
+    ($($range:ty: $size:expr => |$this:ident| ($start:expr, $op:ident, $end:expr);)+) => {
+        $(
+            unsafe impl<T: ToStr> ToStr for $range {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    unwrap_text(self.try_to_str(buffer))
+                }
+
+                #[inline]
+                fn text_len(&self) -> usize {
+                    let $this = self;
+                    text_len::<T>($start, $op, $end)
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let $this = self;
+                    try_to_str::<Self, T>(self, $start, $op, $end, buffer)
+                }
+            }
+        )+
+    };
+}
+
+impl_range!(
+    ops::Range<T>: T::TEXT_SIZE + EXCLUSIVE.len() + T::TEXT_SIZE => |this| (Some(&this.start), EXCLUSIVE, Some(&this.end));
+    ops::RangeInclusive<T>: T::TEXT_SIZE + INCLUSIVE.len() + T::TEXT_SIZE => |this| (Some(this.start()), INCLUSIVE, Some(this.end()));
+    ops::RangeFrom<T>: T::TEXT_SIZE + EXCLUSIVE.len() => |this| (Some(&this.start), EXCLUSIVE, None);
+    ops::RangeTo<T>: EXCLUSIVE.len() + T::TEXT_SIZE => |this| (None, EXCLUSIVE, Some(&this.end));
+    ops::RangeToInclusive<T>: INCLUSIVE.len() + T::TEXT_SIZE => |this| (None, INCLUSIVE, Some(&this.end));
+);
+
+unsafe impl ToStr for ops::RangeFull {
+    const TEXT_SIZE: usize = EXCLUSIVE.len();
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        EXCLUSIVE.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        write_tail(self, buffer, |tail| tail.put_str(EXCLUSIVE))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Error, ToStr};
+use crate::parts::{max_size, unwrap_text, write_tail};
+
+const OK: &str = "Ok(";
+const ERR: &str = "Err(";
+const CLOSE: &str = ")";
+
+///Written as `Ok(value)` or `Err(error)`, same as `Debug`, so that both sides are distinguishable.
+unsafe impl<T: ToStr, E: ToStr> ToStr for Result<T, E> {
+    const TEXT_SIZE: usize = max_size(OK.len() + T::TEXT_SIZE, ERR.len() + E::TEXT_SIZE) + CLOSE.len();
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        match self {
+            Ok(value) => OK.len() + value.text_len() + CLOSE.len(),
+            Err(error) => ERR.len() + error.text_len() + CLOSE.len(),
+        }
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        write_tail(self, buffer, |tail| {
+            tail.put_str(CLOSE)?;
+            match self {
+                Ok(value) => {
+                    tail.put(value)?;
+                    tail.put_str(OK)
+                },
+                Err(error) => {
+                    tail.put(error)?;
+                    tail.put_str(ERR)
+                },
+            }
+        })
+    }
+}
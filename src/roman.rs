@@ -0,0 +1,197 @@
+//!Roman numerals
+//!
+//!`Roman` writes numbers `1..=3999` using `I`, `V`, `X`, `L`, `C`, `D` and `M`.
+//!
+//!`RomanExtended` writes numbers `1..=3999999` without vinculum, using Unicode numerals
+//!`ↁ` (5,000), `ↂ` (10,000), `ↇ` (50,000) and `ↈ` (100,000).
+//!Unicode has no numeral above 100,000, therefore 500,000 and 1,000,000 are written in apostrophus
+//!notation, which the numerals above are ligatures of: `IↃↃↃↃ` and `CCCCIↃↃↃↃ`.
+//!
+//!```
+//!use to_str::{Buffer, Case};
+//!use to_str::roman::{Roman, RomanExtended};
+//!
+//!assert_eq!(Buffer::<15>::fmt(Roman::new(2024u16).unwrap()).as_str(), "MMXXIV");
+//!assert_eq!(Buffer::<15>::fmt(Roman::new(14u8).unwrap().with_case(Case::Lower)).as_str(), "xiv");
+//!assert_eq!(Buffer::<103>::fmt(RomanExtended::new(16000u32).unwrap()).as_str(), "ↂↁM");
+//!assert_eq!(Buffer::<103>::fmt(RomanExtended::new(1_500_000u32).unwrap()).as_str(), "CCCCIↃↃↃↃIↃↃↃↃ");
+//!assert!(Roman::new(0u32).is_none());
+//!assert!(Roman::new(4000u32).is_none());
+//!```
+
+use crate::{buffer_tail, Case, Error, ToStr};
+
+//Symbols of one, five and ten for each decimal place
+static UPPER: [[&str; 3]; 7] = [
+    ["I", "V", "X"],
+    ["X", "L", "C"],
+    ["C", "D", "M"],
+    ["M", "ↁ", "ↂ"],
+    ["ↂ", "ↇ", "ↈ"],
+    ["ↈ", "IↃↃↃↃ", "CCCCIↃↃↃↃ"],
+    ["CCCCIↃↃↃↃ", "", ""],
+];
+static LOWER: [[&str; 3]; 7] = [
+    ["i", "v", "x"],
+    ["x", "l", "c"],
+    ["c", "d", "m"],
+    ["m", "ↁ", "ↂ"],
+    ["ↂ", "ↇ", "ↈ"],
+    ["ↈ", "iↄↄↄↄ", "cccciↄↄↄↄ"],
+    ["cccciↄↄↄↄ", "", ""],
+];
+
+//Indexes of symbols for each decimal digit
+static DIGITS: [&[usize]; 10] = [&[], &[0], &[0, 0], &[0, 0, 0], &[0, 1], &[1], &[1, 0], &[1, 0, 0], &[1, 0, 0, 0], &[0, 2]];
+
+#[inline(always)]
+const fn symbols(case: Case) -> &'static [[&'static str; 3]; 7] {
+    match case {
+        Case::Lower => &LOWER,
+        Case::Upper => &UPPER,
+    }
+}
+
+//Number of bytes in numeral
+fn roman_len(mut num: u32) -> usize {
+    let mut len = 0;
+    for place in UPPER.iter() {
+        len += DIGITS[(num % 10) as usize].iter().map(|symbol| place[*symbol].len()).sum::<usize>();
+        num /= 10;
+    }
+    len
+}
+
+//Writes exactly `roman_len(num)` bytes into `buffer`
+fn write_roman(mut num: u32, case: Case, buffer: &mut [u8]) {
+    let mut cursor = buffer.len();
+    for place in symbols(case).iter() {
+        for symbol in DIGITS[(num % 10) as usize].iter().rev() {
+            let symbol = place[*symbol].as_bytes();
+            cursor -= symbol.len();
+            buffer[cursor..cursor + symbol.len()].copy_from_slice(symbol);
+        }
+        num /= 10;
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///Integer which can be written as Roman numeral
+///
+///Implemented for all integers.
+pub trait Integer: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    fn to_u32(&self) -> Option<u32>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),+) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Integer for $t {
+                #[inline(always)]
+                fn to_u32(&self) -> Option<u32> {
+                    u32::try_from(*self).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_roman {
+    ($($(#[$meta:meta])* $name:ident: $max:literal => $size:literal;)+) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            $(#[$meta])*
+            pub struct $name<T> {
+                value: T,
+                case: Case,
+            }
+
+            impl<T: Integer> $name<T> {
+                ///Greatest number which can be written
+                pub const MAX: u32 = $max;
+
+                #[inline]
+                ///Creates new instance in uppercase, returning `None` if `value` is out of range `1..=MAX`
+                pub fn new(value: T) -> Option<Self> {
+                    match value.to_u32() {
+                        Some(1..=$max) => Some(Self {
+                            value,
+                            case: Case::Upper,
+                        }),
+                        _ => None,
+                    }
+                }
+
+                #[inline(always)]
+                ///Sets case of letters, leaving numerals without lowercase form intact
+                pub const fn with_case(mut self, case: Case) -> Self {
+                    self.case = case;
+                    self
+                }
+
+                #[inline(always)]
+                ///Returns value
+                pub const fn value(&self) -> T {
+                    self.value
+                }
+
+                #[inline(always)]
+                fn num(&self) -> u32 {
+                    //Validated on creation
+                    self.value.to_u32().unwrap_or(0)
+                }
+            }
+
+            unsafe impl<T: Integer> ToStr for $name<T> {
+                const TEXT_SIZE: usize = $size;
+
+                #[inline]
+                fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                    assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+                    let num = self.num();
+                    let start = buffer.len() - roman_len(num);
+                    let buffer = &mut buffer[start..];
+                    write_roman(num, self.case, buffer);
+                    unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    }
+                }
+
+                #[inline(always)]
+                fn text_len(&self) -> usize {
+                    roman_len(self.num())
+                }
+
+                #[inline]
+                fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                    let num = self.num();
+                    let buffer = buffer_tail(buffer, roman_len(num))?;
+                    write_roman(num, self.case, buffer);
+                    Ok(unsafe {
+                        core::str::from_utf8_unchecked(buffer)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_roman!(
+    ///Roman numeral of number `1..=3999`
+    ///
+    ///The longest text is `MMMDCCCLXXXVIII`.
+    Roman: 3999 => 15;
+    ///Roman numeral of number `1..=3999999`, using Unicode numerals and apostrophus for 5,000 and above
+    ///
+    ///The longest text is the one of 3,888,888, taking 103 bytes.
+    RomanExtended: 3999999 => 103;
+);
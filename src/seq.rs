@@ -0,0 +1,284 @@
+//!Formatting of tuples and arrays
+//!
+//!Tuples are written as `(1, 2)` while arrays as `[1, 2, 3]`.
+//!Other styles can be selected via `Joined` wrapper.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::seq::{Joined, Spaced, Style};
+//!
+//!assert_eq!(Buffer::<16>::fmt((1u8, -2i8)).as_str(), "(1, -2)");
+//!assert_eq!(Buffer::<16>::fmt([1u8, 2, 3]).as_str(), "[1, 2, 3]");
+//!assert_eq!(Buffer::<16>::fmt(Joined::<_, Spaced>::new((10u8, 20u8))).as_str(), "10 20");
+//!
+//!struct Version;
+//!
+//!impl Style for Version {
+//!    const OPEN: &'static str = "v";
+//!    const SEPARATOR: &'static str = ".";
+//!    const CLOSE: &'static str = "";
+//!}
+//!
+//!assert_eq!(Buffer::<32>::fmt(Joined::<_, Version>::new((1u8, 20u16, 300u32))).as_str(), "v1.20.300");
+//!```
+
+use crate::{Error, ToStr};
+use crate::parts::{unwrap_text, write_tail, Tail};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+///Describes how sequence of values is delimited
+pub trait Style {
+    ///Text before the first value
+    const OPEN: &'static str;
+    ///Text between values
+    const SEPARATOR: &'static str;
+    ///Text after the last value
+    const CLOSE: &'static str;
+}
+
+#[derive(Debug, Clone, Copy)]
+///Style of tuple: `(1, 2)`
+pub struct Parens;
+
+impl Style for Parens {
+    const OPEN: &'static str = "(";
+    const SEPARATOR: &'static str = ", ";
+    const CLOSE: &'static str = ")";
+}
+
+#[derive(Debug, Clone, Copy)]
+///Style of array: `[1, 2]`
+pub struct Brackets;
+
+impl Style for Brackets {
+    const OPEN: &'static str = "[";
+    const SEPARATOR: &'static str = ", ";
+    const CLOSE: &'static str = "]";
+}
+
+#[derive(Debug, Clone, Copy)]
+///Space separated values: `1 2`
+pub struct Spaced;
+
+impl Style for Spaced {
+    const OPEN: &'static str = "";
+    const SEPARATOR: &'static str = " ";
+    const CLOSE: &'static str = "";
+}
+
+//Style of single element tuple: `(1,)`
+struct UnaryParens;
+
+impl Style for UnaryParens {
+    const OPEN: &'static str = "(";
+    const SEPARATOR: &'static str = ", ";
+    const CLOSE: &'static str = ",)";
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///Sequence of values which can be joined
+///
+///Implemented for tuples up to 12 elements and arrays.
+///Single element tuple is written with trailing comma `(1,)`, unless joined in other style.
+pub trait Sequence: sealed::Sealed {
+    ///Number of values
+    const LEN: usize;
+    ///Sum of values' `TEXT_SIZE`
+    const ITEMS_TEXT_SIZE: usize;
+
+    #[doc(hidden)]
+    fn items_text_len(&self) -> usize;
+
+    #[doc(hidden)]
+    fn put_items(&self, tail: &mut Tail<'_>, separator: &str) -> Option<()>;
+}
+
+#[inline]
+const fn text_size<T: Sequence, S: Style>() -> usize {
+    S::OPEN.len() + T::ITEMS_TEXT_SIZE + T::LEN.saturating_sub(1) * S::SEPARATOR.len() + S::CLOSE.len()
+}
+
+#[inline]
+fn text_len<T: Sequence, S: Style>(value: &T) -> usize {
+    S::OPEN.len() + value.items_text_len() + T::LEN.saturating_sub(1) * S::SEPARATOR.len() + S::CLOSE.len()
+}
+
+#[inline]
+//Writes `items` of the `value` in specified style
+fn try_join<'a, T: Sequence, S: Style, V: ToStr>(value: &V, items: &T, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+    write_tail(value, buffer, |tail| {
+        tail.put_str(S::CLOSE)?;
+        items.put_items(tail, S::SEPARATOR)?;
+        tail.put_str(S::OPEN)
+    })
+}
+
+#[repr(transparent)]
+///Wrapper to format tuple or array in specified `Style`
+pub struct Joined<T, S> {
+    ///Sequence of values
+    pub value: T,
+    _style: PhantomData<S>,
+}
+
+impl<T, S> Joined<T, S> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _style: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, S> Clone for Joined<T, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, S> Copy for Joined<T, S> {}
+
+impl<T: fmt::Debug, S> fmt::Debug for Joined<T, S> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, fmt)
+    }
+}
+
+unsafe impl<T: Sequence, S: Style> ToStr for Joined<T, S> {
+    const TEXT_SIZE: usize = text_size::<T, S>();
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        text_len::<T, S>(&self.value)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        try_join::<T, S, Self>(self, &self.value, buffer)
+    }
+}
+
+impl<T: ToStr, const N: usize> sealed::Sealed for [T; N] {}
+
+impl<T: ToStr, const N: usize> Sequence for [T; N] {
+    const LEN: usize = N;
+    const ITEMS_TEXT_SIZE: usize = T::TEXT_SIZE * N;
+
+    #[inline]
+    fn items_text_len(&self) -> usize {
+        self.iter().map(ToStr::text_len).sum()
+    }
+
+    #[inline]
+    fn put_items(&self, tail: &mut Tail<'_>, separator: &str) -> Option<()> {
+        let mut items = self.iter().rev();
+        if let Some(item) = items.next() {
+            tail.put(item)?;
+            for item in items {
+                tail.put_str(separator)?;
+                tail.put(item)?;
+            }
+        }
+        Some(())
+    }
+}
+
+unsafe impl<T: ToStr, const N: usize> ToStr for [T; N] {
+    const TEXT_SIZE: usize = text_size::<Self, Brackets>();
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline]
+    fn text_len(&self) -> usize {
+        text_len::<Self, Brackets>(self)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        try_join::<Self, Brackets, Self>(self, self, buffer)
+    }
+}
+
+//Writes tuple's fields in reverse order
+macro_rules! put_rev {
+    ($tail:ident, $this:ident, $sep:ident; $idx:tt) => {
+        $tail.put(&$this.$idx)?;
+    };
+    ($tail:ident, $this:ident, $sep:ident; $idx:tt $($rest:tt)+) => {
+        put_rev!($tail, $this, $sep; $($rest)+);
+        $tail.put_str($sep)?;
+        $tail.put(&$this.$idx)?;
+    };
+}
+
+macro_rules! impl_tuple {
+    ($style:ident; $($t:ident $idx:tt),+) => {
+        impl<$($t: ToStr),+> sealed::Sealed for ($($t,)+) {}
+
+        impl<$($t: ToStr),+> Sequence for ($($t,)+) {
+            const LEN: usize = [$($idx),+].len();
+            const ITEMS_TEXT_SIZE: usize = 0 $(+ $t::TEXT_SIZE)+;
+
+            #[inline]
+            fn items_text_len(&self) -> usize {
+                0 $(+ self.$idx.text_len())+
+            }
+
+            #[inline]
+            #[allow(unused_variables)]
+            fn put_items(&self, tail: &mut Tail<'_>, separator: &str) -> Option<()> {
+                put_rev!(tail, self, separator; $($idx)+);
+                Some(())
+            }
+        }
+
+        unsafe impl<$($t: ToStr),+> ToStr for ($($t,)+) {
+            const TEXT_SIZE: usize = text_size::<Self, $style>();
+
+            #[inline]
+            fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+                unwrap_text(self.try_to_str(buffer))
+            }
+
+            #[inline]
+            fn text_len(&self) -> usize {
+                text_len::<Self, $style>(self)
+            }
+
+            #[inline]
+            fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+                try_join::<Self, $style, Self>(self, self, buffer)
+            }
+        }
+    };
+}
+
+impl_tuple!(UnaryParens; A 0);
+impl_tuple!(Parens; A 0, B 1);
+impl_tuple!(Parens; A 0, B 1, C 2);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_tuple!(Parens; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
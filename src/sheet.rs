@@ -0,0 +1,289 @@
+//!Spreadsheet column names and cell references
+//!
+//!Columns are numbered from `1` and written in bijective base-26: `A` to `Z`, then `AA`, `AB` and so on.
+//!
+//!```
+//!use core::num::NonZeroU32;
+//!
+//!use to_str::Buffer;
+//!use to_str::sheet::{CellRef, Column};
+//!
+//!let column = Column::from_number(16384).unwrap();
+//!assert_eq!(Buffer::<7>::fmt(column).as_str(), "XFD");
+//!assert_eq!(Column::parse_str("XFD"), Ok(column));
+//!
+//!let cell = CellRef::new(Column::from_number(2).unwrap(), NonZeroU32::new(12).unwrap());
+//!assert_eq!(Buffer::<17>::fmt(cell).as_str(), "B12");
+//!assert_eq!(CellRef::parse_str("b12"), Ok(cell));
+//!```
+
+use crate::{buffer_tail, Error, ParseError, ToStr};
+use crate::numeric::{decimal_len, write_u64_to_buf};
+
+use core::num::NonZeroU32;
+use core::str::FromStr;
+
+//Number of letters in column name
+const fn column_len(mut num: u32) -> usize {
+    let mut len = 0;
+    while num > 0 {
+        num = (num - 1) / 26;
+        len += 1;
+    }
+    len
+}
+
+//Writes column name, ending at `cursor`
+const fn write_column(mut num: u32, buffer: &mut [u8], mut cursor: usize) -> usize {
+    while num > 0 {
+        num -= 1;
+        cursor -= 1;
+        buffer[cursor] = b'A' + (num % 26) as u8;
+        num /= 26;
+    }
+    cursor
+}
+
+//Parses letters of column name in either case
+const fn parse_column(bytes: &[u8]) -> Result<Column, ParseError> {
+    if bytes.is_empty() {
+        return Err(ParseError::InvalidLength { len: 0 });
+    }
+
+    let mut value = 0u32;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let digit = match bytes[idx] {
+            byte @ b'A'..=b'Z' => byte - b'A' + 1,
+            byte @ b'a'..=b'z' => byte - b'a' + 1,
+            _ => return Err(ParseError::InvalidCharacter { index: idx }),
+        };
+        value = match value.checked_mul(26) {
+            Some(value) => match value.checked_add(digit as u32) {
+                Some(value) => value,
+                None => return Err(ParseError::Overflow),
+            },
+            None => return Err(ParseError::Overflow),
+        };
+        idx += 1;
+    }
+
+    match NonZeroU32::new(value) {
+        Some(value) => Ok(Column(value)),
+        None => Err(ParseError::Overflow),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+///Spreadsheet column name
+///
+///Written in uppercase bijective base-26, from `A` for `1` up to `MWLQKWU` for `u32::MAX`.
+pub struct Column(NonZeroU32);
+
+impl Column {
+    ///First column `A`
+    pub const FIRST: Self = Self(NonZeroU32::MIN);
+    ///Last column `MWLQKWU`
+    pub const MAX: Self = Self(NonZeroU32::MAX);
+
+    #[inline(always)]
+    ///Creates new instance from 1-based column number
+    pub const fn new(number: NonZeroU32) -> Self {
+        Self(number)
+    }
+
+    #[inline(always)]
+    ///Creates new instance from 1-based column number, returning `None` if it is zero
+    pub const fn from_number(number: u32) -> Option<Self> {
+        match NonZeroU32::new(number) {
+            Some(number) => Some(Self(number)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns 1-based column number
+    pub const fn get(&self) -> u32 {
+        self.0.get()
+    }
+
+    #[inline]
+    ///Parses column name in either case
+    pub const fn parse_str(text: &str) -> Result<Self, ParseError> {
+        parse_column(text.as_bytes())
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        column_len(self.0.get())
+    }
+}
+
+impl From<NonZeroU32> for Column {
+    #[inline(always)]
+    fn from(number: NonZeroU32) -> Self {
+        Self(number)
+    }
+}
+
+impl FromStr for Column {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(text)
+    }
+}
+
+unsafe impl ToStr for Column {
+    const TEXT_SIZE: usize = column_len(u32::MAX);
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.len();
+        let buffer = &mut buffer[start..];
+        write_column(self.0.get(), buffer, buffer.len());
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len())?;
+        write_column(self.0.get(), buffer, buffer.len());
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///Cell reference in A1 notation
+///
+///Written as column name followed by 1-based row number: `B12`.
+pub struct CellRef {
+    ///Column of the cell
+    pub column: Column,
+    ///1-based row of the cell
+    pub row: NonZeroU32,
+}
+
+impl CellRef {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(column: Column, row: NonZeroU32) -> Self {
+        Self {
+            column,
+            row,
+        }
+    }
+
+    ///Parses cell reference with column name in either case
+    ///
+    ///Row number must not have leading zeros.
+    pub const fn parse_str(text: &str) -> Result<Self, ParseError> {
+        let bytes = text.as_bytes();
+        let mut split = 0;
+        while split < bytes.len() && bytes[split].is_ascii_alphabetic() {
+            split += 1;
+        }
+        if split == bytes.len() {
+            return Err(ParseError::InvalidLength { len: bytes.len() });
+        } else if split == 0 {
+            return Err(ParseError::InvalidCharacter { index: 0 });
+        }
+
+        let (column, row) = bytes.split_at(split);
+        let column = match parse_column(column) {
+            Ok(column) => column,
+            Err(error) => return Err(error),
+        };
+
+        if row[0] == b'0' {
+            return Err(ParseError::InvalidCharacter { index: split });
+        }
+        let mut value = 0u32;
+        let mut idx = 0;
+        while idx < row.len() {
+            let digit = match row[idx] {
+                byte @ b'0'..=b'9' => byte - b'0',
+                _ => return Err(ParseError::InvalidCharacter { index: split + idx }),
+            };
+            value = match value.checked_mul(10) {
+                Some(value) => match value.checked_add(digit as u32) {
+                    Some(value) => value,
+                    None => return Err(ParseError::Overflow),
+                },
+                None => return Err(ParseError::Overflow),
+            };
+            idx += 1;
+        }
+
+        match NonZeroU32::new(value) {
+            Some(row) => Ok(Self::new(column, row)),
+            None => Err(ParseError::Overflow),
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.column.len() + decimal_len::u32(self.row.get())
+    }
+
+    //Writes exactly `len()` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let cursor = unsafe {
+            write_u64_to_buf(self.row.get() as u64, buffer.as_mut_ptr(), buffer.len() as isize)
+        };
+        write_column(self.column.get(), buffer, cursor as usize);
+    }
+}
+
+impl FromStr for CellRef {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(text)
+    }
+}
+
+unsafe impl ToStr for CellRef {
+    const TEXT_SIZE: usize = Column::TEXT_SIZE + u32::TEXT_SIZE;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - self.len();
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.len())?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
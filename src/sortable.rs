@@ -0,0 +1,288 @@
+//!Order-preserving text of numbers
+//!
+//!`Sortable` writes numbers as fixed-width text, which sorts lexicographically in the same order as numbers.
+//!
+//!- Signed integers are biased by flipping the sign bit;
+//!- `f64` flips the sign bit of positive numbers and all bits of negative ones, ordering them by `f64::total_cmp`.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::sortable::{Base32Hex, Hex, Sortable};
+//!
+//!assert_eq!(Buffer::<3>::fmt(Sortable::<_>::new(-1i8)).as_str(), "127");
+//!assert_eq!(Buffer::<3>::fmt(Sortable::<_>::new(0i8)).as_str(), "128");
+//!assert_eq!(Buffer::<16>::fmt(Sortable::<_, Hex>::new(1.5f64)).as_str(), "bff8000000000000");
+//!assert_eq!(Sortable::<f64, Base32Hex>::decode("BVU0000000000"), Ok(1.5));
+//!```
+
+use crate::{buffer_tail, Error, ParseError, ToStr};
+use crate::numeric::{decimal_len, write_u128_to_buf};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+///Alphabet of sortable text
+///
+///Digits are ordered in ASCII, therefore text sorts the same as its value.
+pub trait Alphabet: sealed::Sealed {
+    ///Digits in ascending order
+    const DIGITS: &'static [u8];
+    ///Number of bits in a single digit, or `0` if radix is not power of two
+    const DIGIT_BITS: u32;
+}
+
+#[derive(Debug, Clone, Copy)]
+///Decimal digits `0-9`
+pub struct Decimal;
+
+#[derive(Debug, Clone, Copy)]
+///Lowercase hex digits `0-9a-f`
+pub struct Hex;
+
+#[derive(Debug, Clone, Copy)]
+///Base32 extended hex digits `0-9A-V` (RFC 4648, section 7)
+pub struct Base32Hex;
+
+impl sealed::Sealed for Decimal {}
+impl sealed::Sealed for Hex {}
+impl sealed::Sealed for Base32Hex {}
+
+impl Alphabet for Decimal {
+    const DIGITS: &'static [u8] = b"0123456789";
+    const DIGIT_BITS: u32 = 0;
+}
+
+impl Alphabet for Hex {
+    const DIGITS: &'static [u8] = b"0123456789abcdef";
+    const DIGIT_BITS: u32 = 4;
+}
+
+impl Alphabet for Base32Hex {
+    const DIGITS: &'static [u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+    const DIGIT_BITS: u32 = 5;
+}
+
+///Number convertible into unsigned key of the same order
+///
+///Implemented for all integers and `f64`.
+pub trait Key: sealed::Sealed + Sized {
+    ///Number of bits in the key
+    const BITS: u32;
+
+    #[doc(hidden)]
+    fn to_key(&self) -> u128;
+
+    #[doc(hidden)]
+    fn from_key(key: u128) -> Self;
+}
+
+macro_rules! impl_unsigned_key {
+    ($($t:ty),+) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Key for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                #[inline(always)]
+                fn to_key(&self) -> u128 {
+                    *self as u128
+                }
+
+                #[inline(always)]
+                fn from_key(key: u128) -> Self {
+                    key as $t
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_signed_key {
+    ($($t:ty as $unsigned:ty),+) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Key for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                #[inline(always)]
+                fn to_key(&self) -> u128 {
+                    (*self as $unsigned ^ <$t>::MIN as $unsigned) as u128
+                }
+
+                #[inline(always)]
+                fn from_key(key: u128) -> Self {
+                    (key as $unsigned ^ <$t>::MIN as $unsigned) as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_unsigned_key!(u8, u16, u32, u64, u128, usize);
+impl_signed_key!(i8 as u8, i16 as u16, i32 as u32, i64 as u64, i128 as u128, isize as usize);
+
+impl sealed::Sealed for f64 {}
+
+impl Key for f64 {
+    const BITS: u32 = u64::BITS;
+
+    #[inline(always)]
+    fn to_key(&self) -> u128 {
+        let bits = self.to_bits();
+        let key = match bits >> 63 {
+            0 => bits | 1 << 63,
+            _ => !bits,
+        };
+        key as u128
+    }
+
+    #[inline(always)]
+    fn from_key(key: u128) -> Self {
+        let key = key as u64;
+        let bits = match key >> 63 {
+            0 => !key,
+            _ => key & !(1 << 63),
+        };
+        f64::from_bits(bits)
+    }
+}
+
+#[inline]
+//Number of digits to write `bits` wide key
+const fn width<A: Alphabet>(bits: u32) -> usize {
+    match A::DIGIT_BITS {
+        0 => decimal_len::u128(u128::MAX >> (u128::BITS - bits)),
+        digit_bits => bits.div_ceil(digit_bits) as usize,
+    }
+}
+
+#[repr(transparent)]
+///Wrapper to write number as fixed-width order-preserving text in specified `Alphabet`
+pub struct Sortable<T, A = Decimal> {
+    ///Number to write
+    pub value: T,
+    _alphabet: PhantomData<A>,
+}
+
+impl<T, A> Sortable<T, A> {
+    #[inline(always)]
+    ///Creates new instance
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _alphabet: PhantomData,
+        }
+    }
+}
+
+impl<T: Key, A: Alphabet> Sortable<T, A> {
+    ///Number of characters in text
+    pub const WIDTH: usize = width::<A>(T::BITS);
+
+    ///Decodes number from text written by this wrapper
+    pub fn decode(text: &str) -> Result<T, ParseError> {
+        let bytes = text.as_bytes();
+        if bytes.len() != Self::WIDTH {
+            return Err(ParseError::InvalidLength { len: bytes.len() });
+        }
+
+        let max = u128::MAX >> (u128::BITS - T::BITS);
+        let mut key = 0u128;
+        for (index, byte) in bytes.iter().enumerate() {
+            let digit = match A::DIGITS.iter().position(|digit| digit == byte) {
+                Some(digit) => digit as u128,
+                None => return Err(ParseError::InvalidCharacter { index }),
+            };
+
+            key = match A::DIGIT_BITS {
+                0 => match key.checked_mul(10).and_then(|key| key.checked_add(digit)) {
+                    Some(key) => key,
+                    None => return Err(ParseError::Overflow),
+                },
+                //Leading digit may hold more bits than the key has
+                digit_bits => match index {
+                    0 if digit >> (T::BITS - (Self::WIDTH as u32 - 1) * digit_bits) != 0 => return Err(ParseError::Overflow),
+                    _ => (key << digit_bits) | digit,
+                },
+            };
+        }
+
+        if key > max {
+            return Err(ParseError::Overflow);
+        }
+        Ok(T::from_key(key))
+    }
+
+    //Writes exactly `WIDTH` bytes into `buffer`
+    #[inline]
+    fn write(&self, buffer: &mut [u8]) {
+        let mut key = self.value.to_key();
+        match A::DIGIT_BITS {
+            0 => {
+                buffer.fill(b'0');
+                unsafe {
+                    write_u128_to_buf(key, buffer.as_mut_ptr(), buffer.len() as isize);
+                }
+            },
+            digit_bits => {
+                let mask = (1 << digit_bits) - 1;
+                for digit in buffer.iter_mut().rev() {
+                    *digit = A::DIGITS[(key & mask) as usize];
+                    key >>= digit_bits;
+                }
+            },
+        }
+    }
+}
+
+impl<T: Clone, A> Clone for Sortable<T, A> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, A> Copy for Sortable<T, A> {}
+
+impl<T: fmt::Debug, A> fmt::Debug for Sortable<T, A> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, fmt)
+    }
+}
+
+unsafe impl<T: Key, A: Alphabet> ToStr for Sortable<T, A> {
+    const TEXT_SIZE: usize = Self::WIDTH;
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        assert!(buffer.len() >= Self::TEXT_SIZE, "Buffer should be sufficient");
+
+        let start = buffer.len() - Self::TEXT_SIZE;
+        let buffer = &mut buffer[start..];
+        self.write(buffer);
+        unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        }
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        Self::TEXT_SIZE
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, Self::TEXT_SIZE)?;
+        self.write(buffer);
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
@@ -0,0 +1,357 @@
+//!Short reversible IDs compatible with [Sqids](https://sqids.org)
+//!
+//!Only ASCII alphabets are supported. Blocklist is supplied by user, default list of Sqids is not bundled.
+//!
+//!```
+//!use to_str::Buffer;
+//!use to_str::sqids::Sqids;
+//!
+//!const SQIDS: Sqids<'static> = Sqids::DEFAULT;
+//!
+//!let mut buffer = Buffer::<256>::new();
+//!assert_eq!(buffer.format(SQIDS.encode([1, 2, 3])), "86Rf07");
+//!
+//!let mut numbers = [0u64; 4];
+//!assert_eq!(SQIDS.decode("86Rf07", &mut numbers), Ok(&[1, 2, 3][..]));
+//!```
+
+use crate::{buffer_tail, Error, ParseError, ToStr};
+use crate::parts::unwrap_text;
+
+///Maximum length of alphabet, as it consists of unique ASCII characters
+const MAX_ALPHABET_LEN: usize = 128;
+
+///Default alphabet of Sqids
+pub const DEFAULT_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+//Consistent shuffle of Sqids
+const fn shuffle(chars: &mut [u8]) {
+    let len = chars.len();
+    let mut idx = 0;
+    let mut rev_idx = len - 1;
+    while rev_idx > 0 {
+        let swap_idx = (idx * rev_idx + chars[idx] as usize + chars[rev_idx] as usize) % len;
+        let tmp = chars[idx];
+        chars[idx] = chars[swap_idx];
+        chars[swap_idx] = tmp;
+        idx += 1;
+        rev_idx -= 1;
+    }
+}
+
+//Number of digits to write `num` in `radix`
+#[inline]
+fn digits_len(mut num: u64, radix: u64) -> usize {
+    let mut len = 1;
+    while num >= radix {
+        num /= radix;
+        len += 1;
+    }
+    len
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Sqids configuration
+pub struct Sqids<'a> {
+    alphabet: [u8; MAX_ALPHABET_LEN],
+    len: u8,
+    min_length: u8,
+    blocklist: &'a [&'a str],
+}
+
+impl Sqids<'static> {
+    ///Configuration with `DEFAULT_ALPHABET`, no minimum length and empty blocklist
+    pub const DEFAULT: Self = match Self::new(DEFAULT_ALPHABET) {
+        Ok(this) => this,
+        Err(_) => panic!("Default alphabet should be valid"),
+    };
+
+    ///Creates new configuration with specified `alphabet`
+    ///
+    ///Alphabet must consist of at least 3 unique ASCII characters.
+    pub const fn new(alphabet: &str) -> Result<Self, ParseError> {
+        let bytes = alphabet.as_bytes();
+        if bytes.len() < 3 || bytes.len() > MAX_ALPHABET_LEN {
+            return Err(ParseError::InvalidLength { len: bytes.len() });
+        }
+
+        let mut this = Self {
+            alphabet: [0; MAX_ALPHABET_LEN],
+            len: bytes.len() as u8,
+            min_length: 0,
+            blocklist: &[],
+        };
+
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let byte = bytes[idx];
+            if !byte.is_ascii() {
+                return Err(ParseError::InvalidCharacter { index: idx });
+            }
+
+            let mut prev_idx = 0;
+            while prev_idx < idx {
+                if bytes[prev_idx] == byte {
+                    return Err(ParseError::InvalidCharacter { index: idx });
+                }
+                prev_idx += 1;
+            }
+
+            this.alphabet[idx] = byte;
+            idx += 1;
+        }
+
+        let (alphabet, _) = this.alphabet.split_at_mut(bytes.len());
+        shuffle(alphabet);
+        Ok(this)
+    }
+}
+
+impl<'a> Sqids<'a> {
+    #[inline(always)]
+    ///Sets minimum length of IDs
+    pub const fn with_min_length(mut self, min_length: u8) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    #[inline(always)]
+    ///Sets words that must not appear in IDs
+    ///
+    ///Words are matched case-insensitively, ignoring ones shorter than 3 characters or with characters outside of alphabet.
+    pub const fn with_blocklist<'b>(self, blocklist: &'b [&'b str]) -> Sqids<'b> {
+        Sqids {
+            alphabet: self.alphabet,
+            len: self.len,
+            min_length: self.min_length,
+            blocklist,
+        }
+    }
+
+    #[inline(always)]
+    fn alphabet(&self) -> &[u8] {
+        &self.alphabet[..self.len as usize]
+    }
+
+    #[inline(always)]
+    ///Returns wrapper to write ID of `numbers`
+    pub const fn encode<const K: usize>(&self, numbers: [u64; K]) -> SqidsId<'_, K> {
+        SqidsId {
+            sqids: self,
+            numbers,
+        }
+    }
+
+    //Returns alphabet rotated by `offset` and reversed
+    #[inline]
+    fn rotated(&self, offset: usize) -> [u8; MAX_ALPHABET_LEN] {
+        let alphabet = self.alphabet();
+        let len = alphabet.len();
+        let mut result = [0; MAX_ALPHABET_LEN];
+        result[..len - offset].copy_from_slice(&alphabet[offset..]);
+        result[len - offset..len].copy_from_slice(&alphabet[..offset]);
+        result[..len].reverse();
+        result
+    }
+
+    #[inline]
+    fn is_blocked(&self, id: &[u8]) -> bool {
+        let alphabet = self.alphabet();
+        for word in self.blocklist.iter().map(|word| word.as_bytes()) {
+            if word.len() < 3 || word.len() > id.len() {
+                continue;
+            }
+            let is_valid = word.iter().all(|byte| alphabet.iter().any(|ch| ch.eq_ignore_ascii_case(byte)));
+            if !is_valid {
+                continue;
+            }
+
+            let is_blocked = if id.len() <= 3 || word.len() <= 3 {
+                id.eq_ignore_ascii_case(word)
+            } else if word.iter().any(u8::is_ascii_digit) {
+                id[..word.len()].eq_ignore_ascii_case(word) || id[id.len() - word.len()..].eq_ignore_ascii_case(word)
+            } else {
+                id.windows(word.len()).any(|part| part.eq_ignore_ascii_case(word))
+            };
+            if is_blocked {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    //Length of ID for `numbers`
+    #[inline]
+    fn text_len(&self, numbers: &[u64]) -> usize {
+        if numbers.is_empty() {
+            return 0;
+        }
+
+        let radix = self.len as u64 - 1;
+        let len = numbers.len() + numbers.iter().map(|num| digits_len(*num, radix)).sum::<usize>();
+        len.max(self.min_length as usize)
+    }
+
+    //Writes ID with alphabet rotated by `offset`, filling whole `buffer` of `text_len()`
+    fn write_attempt(&self, numbers: &[u64], offset: usize, buffer: &mut [u8]) {
+        let len = self.len as usize;
+        let mut alphabet = self.rotated(offset);
+        let alphabet = &mut alphabet[..len];
+
+        buffer[0] = alphabet[len - 1];
+        let mut cursor = 1;
+        for (idx, num) in numbers.iter().enumerate() {
+            let radix = len as u64 - 1;
+            let digits_len = digits_len(*num, radix);
+            let mut num = *num;
+            for digit in buffer[cursor..cursor + digits_len].iter_mut().rev() {
+                *digit = alphabet[1 + (num % radix) as usize];
+                num /= radix;
+            }
+            cursor += digits_len;
+
+            if idx + 1 < numbers.len() {
+                buffer[cursor] = alphabet[0];
+                cursor += 1;
+                shuffle(alphabet);
+            }
+        }
+
+        if cursor < buffer.len() {
+            buffer[cursor] = alphabet[0];
+            cursor += 1;
+            while cursor < buffer.len() {
+                shuffle(alphabet);
+                let part_len = (buffer.len() - cursor).min(len);
+                buffer[cursor..cursor + part_len].copy_from_slice(&alphabet[..part_len]);
+                cursor += part_len;
+            }
+        }
+    }
+
+    //Writes ID, filling whole `buffer` of `text_len()`
+    //
+    //Fails with `Error::Unrepresentable` once blocklist rejects every attempt.
+    fn write(&self, numbers: &[u64], buffer: &mut [u8]) -> Result<(), Error> {
+        if numbers.is_empty() {
+            return Ok(());
+        }
+
+        let alphabet = self.alphabet();
+        let len = alphabet.len();
+        let offset = numbers.iter().enumerate().fold(numbers.len(), |acc, (idx, num)| {
+            alphabet[(num % len as u64) as usize] as usize + idx + acc
+        });
+
+        for increment in 0..=len {
+            self.write_attempt(numbers, (offset + increment) % len, buffer);
+            if !self.is_blocked(buffer) {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Unrepresentable)
+    }
+
+    ///Decodes numbers of ID into `numbers`, returning decoded part
+    ///
+    ///Returns `ParseError::Overflow` if number doesn't fit `u64` or there is more than `numbers.len()` of them.
+    pub fn decode<'b>(&self, id: &str, numbers: &'b mut [u64]) -> Result<&'b [u64], ParseError> {
+        let id = id.as_bytes();
+        let alphabet = self.alphabet();
+        let len = alphabet.len();
+
+        let mut offset = 0;
+        for (index, byte) in id.iter().enumerate() {
+            match alphabet.iter().position(|ch| ch == byte) {
+                Some(position) if index == 0 => offset = position,
+                Some(_) => (),
+                None => return Err(ParseError::InvalidCharacter { index }),
+            }
+        }
+
+        let mut alphabet = self.rotated(offset);
+        let alphabet = &mut alphabet[..len];
+        let mut rest = id.get(1..).unwrap_or_default();
+        let mut count = 0;
+        while !rest.is_empty() {
+            let (chunk, tail) = match rest.iter().position(|byte| *byte == alphabet[0]) {
+                Some(position) => (&rest[..position], Some(&rest[position + 1..])),
+                None => (rest, None),
+            };
+            //Start of padding
+            if chunk.is_empty() {
+                break;
+            }
+
+            let slot = numbers.get_mut(count).ok_or(ParseError::Overflow)?;
+            let radix = len as u64 - 1;
+            let mut num = 0u64;
+            for byte in chunk {
+                let digit = alphabet[1..].iter().position(|ch| ch == byte).unwrap_or_default() as u64;
+                num = num.checked_mul(radix).and_then(|num| num.checked_add(digit)).ok_or(ParseError::Overflow)?;
+            }
+            *slot = num;
+            count += 1;
+
+            match tail {
+                Some(tail) => {
+                    shuffle(alphabet);
+                    rest = tail;
+                },
+                None => break,
+            }
+        }
+
+        Ok(&numbers[..count])
+    }
+}
+
+impl Default for Sqids<'static> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+///ID of `K` numbers
+///
+///`TEXT_SIZE` is the worst case of binary alphabet and minimum length of 255,
+///while actual length is reported by `text_len()`.
+///
+///If blocklist rejects every possible ID, `try_to_str()` fails with `Error::Unrepresentable`,
+///while `to_str()` panics.
+pub struct SqidsId<'a, const K: usize> {
+    sqids: &'a Sqids<'a>,
+    ///Numbers to encode
+    pub numbers: [u64; K],
+}
+
+unsafe impl<const K: usize> ToStr for SqidsId<'_, K> {
+    //Prefix, separators and up to 64 binary digits of each number
+    const TEXT_SIZE: usize = match K {
+        0 => 0,
+        _ => if 65 * K > u8::MAX as usize { 65 * K } else { u8::MAX as usize },
+    };
+
+    #[inline]
+    fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        unwrap_text(self.try_to_str(buffer))
+    }
+
+    #[inline(always)]
+    fn text_len(&self) -> usize {
+        self.sqids.text_len(&self.numbers)
+    }
+
+    #[inline]
+    fn try_to_str<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, Error> {
+        let buffer = buffer_tail(buffer, self.text_len())?;
+        self.sqids.write(&self.numbers, buffer)?;
+        Ok(unsafe {
+            core::str::from_utf8_unchecked(buffer)
+        })
+    }
+}
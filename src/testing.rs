@@ -0,0 +1,250 @@
+//!Contract checks for `ToStr` implementations
+//!
+//!Runs implementation against poisoned, canary-filled and exact-size buffers, verifying that:
+//!
+//!- Text lies inside the buffer and ends at its end;
+//!- Text does not exceed `TEXT_SIZE`;
+//!- Text is valid UTF-8;
+//!- Bytes before the text are not modified;
+//!- `text_len()` is exact, which requires to override it when `TEXT_SIZE` exceeds `256` bytes;
+//!- All methods of `ToStr` produce the same text.
+//!
+//!```
+//!to_str::testing::assert_contract(&u64::MAX);
+//!to_str::testing::assert_contract(&i8::MIN);
+//!```
+
+use crate::{Error, ToStr};
+
+use core::{fmt, mem, str};
+
+///Maximum `TEXT_SIZE` supported by checks
+pub const CAPACITY: usize = 1024;
+///Byte used to fill buffer in order to detect text including unwritten bytes.
+///
+///It is never valid within UTF-8.
+pub const POISON: u8 = 0xFF;
+///Byte used to fill buffer in order to detect writes outside of the text.
+///
+///It is never valid within UTF-8.
+pub const CANARY: u8 = 0xC1;
+//Extra space in front of `TEXT_SIZE` to detect writes before the text.
+const PADDING: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Violation of `ToStr` contract
+pub enum Violation {
+    ///`TEXT_SIZE` exceeds `CAPACITY`, therefore it cannot be checked
+    Unsupported,
+    ///Returned text doesn't lie inside the buffer
+    OutOfBounds,
+    ///Returned text doesn't end at the end of the buffer
+    NotAtEnd,
+    ///Returned text exceeds `TEXT_SIZE`
+    TooLong {
+        ///Length of returned text
+        len: usize,
+    },
+    ///Returned text is not valid UTF-8
+    InvalidUtf8,
+    ///Byte before returned text has been modified
+    CanaryOverwritten {
+        ///Index of modified byte
+        index: usize,
+    },
+    ///Method returned text different from `to_str()`
+    Inconsistent {
+        ///Name of the method
+        method: &'static str,
+    },
+    ///`text_len()` doesn't match length of the text
+    TextLen {
+        ///Length of the text
+        expected: usize,
+        ///Length returned by `text_len()`
+        actual: usize,
+    },
+    ///`try_to_str()` returned unexpected result for buffer of given size
+    TryToStr {
+        ///Size of the buffer
+        available: usize,
+        ///Returned error, if any
+        error: Option<Error>,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsupported => fmt.write_fmt(format_args!("TEXT_SIZE exceeds {CAPACITY}")),
+            Self::OutOfBounds => fmt.write_str("Text is outside of the buffer"),
+            Self::NotAtEnd => fmt.write_str("Text doesn't end at the end of the buffer"),
+            Self::TooLong { len } => fmt.write_fmt(format_args!("Text of {len} bytes exceeds TEXT_SIZE")),
+            Self::InvalidUtf8 => fmt.write_str("Text is not valid UTF-8"),
+            Self::CanaryOverwritten { index } => fmt.write_fmt(format_args!("Byte {index} before text has been modified")),
+            Self::Inconsistent { method } => fmt.write_fmt(format_args!("{method}() returned different text")),
+            Self::TextLen { expected, actual } => fmt.write_fmt(format_args!("text_len() returned {actual}, but text has {expected} bytes")),
+            Self::TryToStr { available, error: Some(error) } => fmt.write_fmt(format_args!("try_to_str() failed with buffer of {available} bytes: {error}")),
+            Self::TryToStr { available, error: None } => fmt.write_fmt(format_args!("try_to_str() succeeded with buffer of {available} bytes")),
+        }
+    }
+}
+
+//Text captured by the first write, used as reference for the rest of checks.
+struct Expected {
+    storage: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Expected {
+    fn as_bytes(&self) -> &[u8] {
+        &self.storage[..self.len]
+    }
+
+    fn compare(&self, text: &[u8], method: &'static str) -> Result<(), Violation> {
+        if self.as_bytes() == text {
+            Ok(())
+        } else {
+            Err(Violation::Inconsistent {
+                method,
+            })
+        }
+    }
+}
+
+#[inline(always)]
+//Returns address and length of the text, so that buffer can be inspected after the write.
+fn locate(text: &str) -> (usize, usize) {
+    (text.as_ptr() as usize, text.len())
+}
+
+//Checks text written into `buffer`, which was filled with `fill` prior to the write.
+//
+//Returns text's bytes on success.
+fn check_text<T: ?Sized + ToStr>(buffer: &[u8], (text_start, text_len): (usize, usize), fill: u8) -> Result<&[u8], Violation> {
+    let buffer_start = buffer.as_ptr() as usize;
+    let buffer_end = buffer_start + buffer.len();
+
+    if text_start < buffer_start || text_start + text_len > buffer_end {
+        return Err(Violation::OutOfBounds);
+    } else if text_start + text_len != buffer_end {
+        return Err(Violation::NotAtEnd);
+    } else if text_len > T::TEXT_SIZE {
+        return Err(Violation::TooLong {
+            len: text_len,
+        });
+    }
+
+    let (prefix, text) = buffer.split_at(text_start - buffer_start);
+    if str::from_utf8(text).is_err() {
+        return Err(Violation::InvalidUtf8);
+    }
+
+    match prefix.iter().position(|byte| *byte != fill) {
+        Some(index) => Err(Violation::CanaryOverwritten {
+            index,
+        }),
+        None => Ok(text),
+    }
+}
+
+//Writes `value` into buffer of `size` bytes filled with `fill`, returning written text.
+fn write<'a, T: ?Sized + ToStr>(value: &T, storage: &'a mut [u8], size: usize, fill: u8) -> Result<&'a [u8], Violation> {
+    let buffer = &mut storage[..size];
+    buffer.fill(fill);
+    let text = locate(value.to_str(buffer));
+    check_text::<T>(&storage[..size], text, fill)
+}
+
+///Verifies that `value` satisfies `ToStr` contract.
+pub fn verify<T: ?Sized + ToStr>(value: &T) -> Result<(), Violation> {
+    if T::TEXT_SIZE > CAPACITY {
+        return Err(Violation::Unsupported);
+    }
+
+    let mut storage = [0u8; CAPACITY + PADDING];
+    let mut expected = Expected {
+        storage: [0; CAPACITY],
+        len: 0,
+    };
+
+    let text = write(value, &mut storage, T::TEXT_SIZE + PADDING, POISON)?;
+    expected.len = text.len();
+    expected.storage[..text.len()].copy_from_slice(text);
+    let len = expected.len;
+
+    for (size, fill) in [(T::TEXT_SIZE + PADDING, CANARY), (T::TEXT_SIZE, POISON), (T::TEXT_SIZE, CANARY)] {
+        let text = write(value, &mut storage, size, fill)?;
+        expected.compare(text, "to_str")?;
+    }
+
+    let actual = value.text_len();
+    if actual != len {
+        return Err(Violation::TextLen {
+            expected: len,
+            actual,
+        });
+    }
+
+    {
+        let buffer = &mut storage[..T::TEXT_SIZE + PADDING];
+        buffer.fill(CANARY);
+        let buffer = unsafe {
+            mem::transmute::<&mut [u8], &mut [mem::MaybeUninit<u8>]>(buffer)
+        };
+        let text = locate(value.to_str_uninit(buffer));
+        let text = check_text::<T>(&storage[..T::TEXT_SIZE + PADDING], text, CANARY)?;
+        expected.compare(text, "to_str_uninit")?;
+    }
+
+    for size in [len, T::TEXT_SIZE + PADDING] {
+        let buffer = &mut storage[..size];
+        buffer.fill(CANARY);
+        match value.try_to_str(buffer) {
+            Ok(text) => {
+                let text = locate(text);
+                let text = check_text::<T>(&storage[..size], text, CANARY)?;
+                expected.compare(text, "try_to_str")?;
+            },
+            Err(error) => return Err(Violation::TryToStr {
+                available: size,
+                error: Some(error),
+            }),
+        }
+    }
+
+    if let Some(size) = len.checked_sub(1) {
+        match value.try_to_str(&mut storage[..size]) {
+            Err(Error::BufferTooSmall { required, available }) if required == len && available == size => (),
+            Err(error) => return Err(Violation::TryToStr {
+                available: size,
+                error: Some(error),
+            }),
+            Ok(_) => return Err(Violation::TryToStr {
+                available: size,
+                error: None,
+            }),
+        }
+    }
+
+    let buffer = &mut storage[..T::TEXT_SIZE];
+    buffer.fill(CANARY);
+    if value.to_str_front(buffer) != len {
+        return Err(Violation::Inconsistent {
+            method: "to_str_front",
+        });
+    }
+    expected.compare(&buffer[..len], "to_str_front")
+}
+
+#[track_caller]
+///Asserts that `value` satisfies `ToStr` contract.
+///
+///# Panics
+///
+///On any violation of the contract.
+pub fn assert_contract<T: ?Sized + ToStr>(value: &T) {
+    if let Err(violation) = verify(value) {
+        panic!("ToStr contract violation: {violation}");
+    }
+}
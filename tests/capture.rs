@@ -0,0 +1,67 @@
+use to_str::{Bounded, Buffer, FmtBuffer, ToStr, Truncation};
+use to_str::testing::assert_contract;
+
+use core::fmt::Write;
+
+#[test]
+fn should_capture_display_and_debug() {
+    let mut text = FmtBuffer::<16>::new();
+    assert!(text.is_empty());
+    assert_eq!(write!(text, "{}-{:?}", 1.5, "a"), Ok(()));
+    assert_eq!(text.as_str(), "1.5-\"a\"");
+    assert_eq!(text.len(), 7);
+    assert!(!text.is_truncated());
+    assert_eq!(format!("{:>9}", text), "  1.5-\"a\"");
+
+    text.clear();
+    assert_eq!(write!(text, "{}", "0123456789abcdef"), Ok(()));
+    assert_eq!(text.as_str(), "0123456789abcdef");
+    assert!(!text.is_truncated());
+    assert!(write!(text, "!").is_err());
+    assert!(text.is_truncated());
+    assert_eq!(text.as_str(), "0123456789abcdef");
+}
+
+#[test]
+fn should_truncate_according_to_policy() {
+    let text = FmtBuffer::<4>::capture("ab€cd");
+    assert_eq!(text.as_str(), "ab");
+    assert_eq!(text.as_bytes(), b"ab");
+    assert!(text.is_truncated());
+
+    let mut text = FmtBuffer::<4>::with_policy(Truncation::BYTE);
+    assert!(write!(text, "ab€cd").is_err());
+    assert_eq!(text.as_bytes(), &"ab€".as_bytes()[..4]);
+    assert_eq!(text.as_str(), "ab");
+
+    let mut text = FmtBuffer::<6>::with_policy(Truncation::CHAR.with_ellipsis("…"));
+    assert!(write!(text, "ab€cd").is_err());
+    assert_eq!(text.as_str(), "ab…");
+
+    let mut text = FmtBuffer::<6>::with_policy(Truncation::BYTE.with_ellipsis("."));
+    assert!(write!(text, "abc€d").is_err());
+    assert_eq!(text.as_str(), "abc.");
+
+    let mut text = FmtBuffer::<2>::with_policy(Truncation::CHAR.with_ellipsis("..."));
+    assert!(write!(text, "abc").is_err());
+    assert_eq!(text.as_str(), "ab");
+}
+
+#[test]
+fn should_format_bounded_display() {
+    assert_eq!(Bounded::<f64, 8>::TEXT_SIZE, 8);
+    assert_eq!(Buffer::<8>::fmt(Bounded::<_, 8>::new(0.25)).as_str(), "0.25");
+    assert_eq!(Buffer::<8>::fmt(Bounded::<_, 8>::new('€')).as_str(), "€");
+    assert_eq!(Buffer::<4>::fmt(Bounded::<_, 4>::new("a€€")).as_str(), "a€");
+
+    let bounded = Bounded::<_, 4>::new("long text").with_policy(Truncation::CHAR.with_ellipsis(".."));
+    assert_eq!(bounded.text_len(), 4);
+    assert_eq!(Buffer::<4>::fmt(bounded).as_str(), "lo..");
+
+    let mut buffer = [0u8; 3];
+    assert!(bounded.try_to_str(&mut buffer).is_err());
+
+    assert_contract(&bounded);
+    assert_contract(&Bounded::<_, 16>::new(format_args!("{:?}", Some(1))));
+    assert_contract(&Bounded::<_, 2>::new(""));
+}
@@ -0,0 +1,101 @@
+use to_str::{AnyNum, Bounded, Buffer, Buffer64, Displayed, ToStr};
+use to_str::encoding::{Base64, HexBytes, Separator};
+use to_str::id::Uuid;
+use to_str::sortable::Sortable;
+
+use core::num;
+
+#[test]
+fn should_honour_formatter_flags() {
+    for num in [i64::MIN, -100, -1, 0, 1, 100, i64::MAX] {
+        assert_eq!(format!("{}", Displayed(num)), format!("{}", num));
+        assert_eq!(format!("{:>8}", Displayed(num)), format!("{:>8}", num));
+        assert_eq!(format!("{:<8}", Displayed(num)), format!("{:<8}", num));
+        assert_eq!(format!("{:_^9}", Displayed(num)), format!("{:_^9}", num));
+        assert_eq!(format!("{:+}", Displayed(num)), format!("{:+}", num));
+        assert_eq!(format!("{:08}", Displayed(num)), format!("{:08}", num));
+        assert_eq!(format!("{:+08}", Displayed(num)), format!("{:+08}", num));
+        assert_eq!(format!("{:>8}", Buffer64::fmt(num)), format!("{:>8}", num));
+        assert_eq!(format!("{:<8}", Buffer64::fmt(num)), format!("{:<8}", num));
+    }
+
+    let ptr = 0xab as *const u8;
+    assert_eq!(format!("{:>6}", Displayed(ptr)), "  0xab");
+    assert_eq!(format!("{:.3}", Displayed(ptr)), "0xa");
+    assert_eq!(format!("{:>6}", Buffer::<{<*const u8>::TEXT_SIZE}>::fmt(ptr)), "  0xab");
+}
+
+#[test]
+fn should_format_text_bigger_than_scratch_buffer() {
+    struct Huge<const N: usize>;
+
+    unsafe impl<const N: usize> ToStr for Huge<N> {
+        const TEXT_SIZE: usize = N;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let offset = buffer.len() - Self::TEXT_SIZE;
+            buffer[offset..].fill(b'h');
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    assert_eq!(Displayed(Huge::<300>).to_string(), "h".repeat(300));
+    assert_eq!(format!("{:>302}", Displayed(Huge::<300>)), format!("  {}", "h".repeat(300)));
+    assert_eq!(Displayed(Huge::<1024>).to_string(), "h".repeat(1024));
+
+    let bytes = [0xabu8; 128];
+    let hex = HexBytes::new(bytes).with_separator(Separator::Colon);
+    assert_eq!(Displayed(hex).to_string(), ["ab"; 128].join(":"));
+    let base64 = Base64::new([0u8; 600]);
+    assert_eq!(Displayed(base64).to_string(), "A".repeat(800));
+    assert_eq!(Displayed(Bounded::<_, 300>::new("x".repeat(290))).to_string(), "x".repeat(290));
+}
+
+#[test]
+fn should_fail_on_text_bigger_than_stack_buffer() {
+    use core::fmt::Write;
+
+    struct Huge;
+
+    unsafe impl ToStr for Huge {
+        const TEXT_SIZE: usize = 1025;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let offset = buffer.len() - Self::TEXT_SIZE;
+            buffer[offset..].fill(b'h');
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    let mut out = String::new();
+    assert!(write!(out, "{}", Displayed(Huge)).is_err());
+    assert!(write!(out, "{:>2000}", Displayed(Huge)).is_err());
+    assert!(write!(out, "{}", Displayed(Base64::new([0u8; 1024]))).is_err());
+}
+
+#[test]
+fn should_apply_sign_only_to_integers() {
+    let sortable = Sortable::<u16>::new(5);
+    assert_eq!(format!("{:+}", Displayed(sortable)), "00005");
+    assert_eq!(format!("{:07}", Displayed(sortable)), "00005  ");
+    assert_eq!(format!("{:>7}", Displayed(sortable)), "  00005");
+    assert_eq!(format!("{:>7}", Buffer::<5>::fmt(sortable)), "  00005");
+
+    let uuid = Uuid::from_u128(0x12345678_1234_1234_1234_123456789012).simple();
+    assert_eq!(format!("{:+}", Displayed(uuid)), "12345678123412341234123456789012");
+    assert_eq!(format!("{:+}", Displayed(num::Wrapping(5u8))), "+5");
+    assert_eq!(format!("{:+04}", Displayed(AnyNum::from(-5i8))), "-005");
+    assert_eq!(format!("{:+}", Displayed(1.5f64)), "1.5");
+
+}
+
+#[test]
+fn should_pad_buffer_as_text() {
+    let mut buffer = Buffer::<32>::new();
+    buffer.write(-5i64);
+    assert_eq!(format!("{}", buffer), "-5");
+    assert_eq!(format!("{:+04}", buffer), "-5  ");
+    assert_eq!(format!("{:>4}", buffer), "  -5");
+    assert_eq!(format!("{:.1}", buffer), "-");
+    assert_eq!(format!("{:*^6}", Buffer::<3>::fmt_u8(5)), "**5***");
+}
@@ -0,0 +1,39 @@
+use to_str::{AnyNum, Buffer, DynToStr, ToStr};
+use to_str::testing::assert_contract;
+
+#[test]
+fn should_format_heterogeneous_values() {
+    let values: [&dyn DynToStr; 4] = [&u8::MAX, &i64::MIN, &AnyNum::from(-5i8), &(usize::MAX as *const u8)];
+    let expected = [u8::MAX.to_string(), i64::MIN.to_string(), "-5".to_owned(), format!("{:p}", usize::MAX as *const u8)];
+    let sizes = [u8::TEXT_SIZE, i64::TEXT_SIZE, AnyNum::TEXT_SIZE, <*const u8>::TEXT_SIZE];
+
+    let mut buffer = [0u8; 64];
+    for ((value, expected), size) in values.iter().zip(expected.iter()).zip(sizes) {
+        assert_eq!(value.text_size(), size);
+        assert_eq!(value.dyn_text_len(), expected.len());
+        assert_eq!(value.dyn_to_str(&mut buffer), expected);
+        assert_eq!(value.dyn_try_to_str(&mut buffer[..expected.len()]), Ok(expected.as_str()));
+        assert!(value.dyn_try_to_str(&mut buffer[..expected.len() - 1]).is_err());
+    }
+}
+
+#[test]
+fn should_format_any_num() {
+    assert_eq!(AnyNum::TEXT_SIZE, i128::TEXT_SIZE);
+
+    let values = [
+        AnyNum::from(u8::MAX), AnyNum::from(u16::MAX), AnyNum::from(u32::MAX), AnyNum::from(u64::MAX), AnyNum::from(u128::MAX), AnyNum::from(usize::MAX),
+        AnyNum::from(i8::MIN), AnyNum::from(i16::MIN), AnyNum::from(i32::MIN), AnyNum::from(i64::MIN), AnyNum::from(i128::MIN), AnyNum::from(isize::MIN),
+        AnyNum::from(-1.5f32), AnyNum::from(f64::MIN_POSITIVE),
+    ];
+    let expected = [
+        u8::MAX.to_string(), u16::MAX.to_string(), u32::MAX.to_string(), u64::MAX.to_string(), u128::MAX.to_string(), usize::MAX.to_string(),
+        i8::MIN.to_string(), i16::MIN.to_string(), i32::MIN.to_string(), i64::MIN.to_string(), i128::MIN.to_string(), isize::MIN.to_string(),
+        "-1.5".to_owned(), format!("{:e}", f64::MIN_POSITIVE),
+    ];
+
+    for (value, expected) in values.iter().zip(expected.iter()) {
+        assert_contract(value);
+        assert_eq!(Buffer::<{AnyNum::TEXT_SIZE}>::fmt(value).as_str(), expected);
+    }
+}
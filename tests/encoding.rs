@@ -0,0 +1,302 @@
+use to_str::{Buffer, Case, Error, FmtBuffer, ToStr};
+use to_str::encoding::{Base32, Base32Alphabet, Base58, Base64, Base64Alphabet, EncodeSet, HexBytes, PercentEncoded, Separator, Z85};
+use to_str::testing::assert_contract;
+
+fn hex(bytes: &[u8], separator: &str) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect::<Vec<_>>().join(separator)
+}
+
+#[test]
+fn should_encode_hex_bytes() {
+    assert_eq!(<HexBytes<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<HexBytes<[u8; 1]>>::TEXT_SIZE, 2);
+    assert_eq!(<HexBytes<[u8; 32]>>::TEXT_SIZE, 95);
+
+    let bytes = [0x00, 0x01, 0x7f, 0x80, 0xab, 0xff];
+    let value = HexBytes::new(bytes);
+    let mut buffer = Buffer::<{ <HexBytes<[u8; 6]>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(value), "00017f80abff");
+    assert_eq!(buffer.format(value.with_case(Case::Upper)), "00017F80ABFF");
+    assert_eq!(buffer.format(value.with_separator(Separator::Colon)), "00:01:7f:80:ab:ff");
+    assert_eq!(buffer.format(value.with_separator(Separator::Hyphen)), "00-01-7f-80-ab-ff");
+    assert_eq!(buffer.format(value.with_separator(Separator::Space).with_case(Case::Upper)), "00 01 7F 80 AB FF");
+    assert_eq!(buffer.format(value.reversed()), "ffab807f0100");
+    assert_eq!(buffer.format(value.reversed().with_separator(Separator::Colon)), "ff:ab:80:7f:01:00");
+    assert_eq!(buffer.format(HexBytes::new([0xa5])), "a5");
+    assert_eq!(buffer.format(HexBytes::new([]).with_separator(Separator::Colon)), "");
+
+    assert_eq!(value.text_len(), 12);
+    assert_eq!(value.try_to_str(&mut [0u8; 11]), Err(Error::BufferTooSmall { required: 12, available: 11 }));
+
+    for case in [Case::Lower, Case::Upper] {
+        for separator in [None, Some(Separator::Colon), Some(Separator::Space)] {
+            let mut value = value.with_case(case);
+            value.separator = separator;
+            assert_contract(&value);
+            assert_contract(&value.reversed());
+        }
+    }
+    assert_contract(&HexBytes::new([]));
+    assert_contract(&HexBytes::new([0u8]).with_separator(Separator::Colon));
+}
+
+#[test]
+fn should_encode_digests() {
+    let sha256: [u8; 32] = core::array::from_fn(|idx| (idx * 37) as u8);
+    let sha512: [u8; 64] = core::array::from_fn(|idx| (idx * 101 + 3) as u8);
+
+    let mut buffer = Buffer::<{ <HexBytes<[u8; 32]>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.write(HexBytes::new(sha256)), hex(&sha256, ""));
+    assert_eq!(buffer.as_str(), hex(&sha256, ""));
+
+    //Exceeds 255 bytes of capacity
+    let mut buffer = Buffer::<{ <HexBytes<[u8; 128]>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.write(HexBytes::new(sha512).with_separator(Separator::Colon)), hex(&sha512, ":"));
+    assert_eq!(buffer.as_str(), hex(&sha512, ":"));
+
+    let wide: [u8; 128] = core::array::from_fn(|idx| idx as u8 ^ 0x5a);
+    assert_eq!(Buffer::<{ <HexBytes<[u8; 128]>>::TEXT_SIZE }>::fmt(HexBytes::new(wide).with_separator(Separator::Space)).as_str(), hex(&wide, " "));
+
+    assert_contract(&HexBytes::new(sha256));
+    assert_contract(&HexBytes::new(sha512).with_separator(Separator::Hyphen).reversed());
+}
+
+#[test]
+fn should_encode_base64() {
+    assert_eq!(<Base64<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<Base64<[u8; 1]>>::TEXT_SIZE, 4);
+    assert_eq!(<Base64<[u8; 3]>>::TEXT_SIZE, 4);
+    assert_eq!(<Base64<[u8; 4]>>::TEXT_SIZE, 8);
+    assert_eq!(<Base64<[u8; 32]>>::TEXT_SIZE, 44);
+
+    let mut buffer = Buffer::<8>::new();
+    //RFC 4648 test vectors
+    assert_eq!(buffer.format(Base64::new(*b"")), "");
+    assert_eq!(buffer.format(Base64::new(*b"f")), "Zg==");
+    assert_eq!(buffer.format(Base64::new(*b"fo")), "Zm8=");
+    assert_eq!(buffer.format(Base64::new(*b"foo")), "Zm9v");
+    assert_eq!(buffer.format(Base64::new(*b"foob")), "Zm9vYg==");
+    assert_eq!(buffer.format(Base64::new(*b"fooba")), "Zm9vYmE=");
+    assert_eq!(buffer.format(Base64::new(*b"foobar")), "Zm9vYmFy");
+
+    assert_eq!(buffer.format(Base64::new(*b"f").unpadded()), "Zg");
+    assert_eq!(buffer.format(Base64::new(*b"fo").unpadded()), "Zm8");
+    assert_eq!(buffer.format(Base64::new(*b"foo").unpadded()), "Zm9v");
+    assert_eq!(buffer.format(Base64::new([0xfb, 0xff])), "+/8=");
+    assert_eq!(buffer.format(Base64::new([0xfb, 0xff]).with_alphabet(Base64Alphabet::UrlSafe)), "-_8=");
+    assert_eq!(buffer.format(Base64::new([0xfb, 0xff]).with_alphabet(Base64Alphabet::UrlSafe).unpadded().constant_time()), "-_8");
+
+    assert_eq!(Base64::new(*b"fooba").unpadded().text_len(), 7);
+    assert_eq!(Base64::new(*b"fooba").try_to_str(&mut [0u8; 7]), Err(Error::BufferTooSmall { required: 8, available: 7 }));
+
+    assert_contract(&Base64::new(*b""));
+    assert_contract(&Base64::new(*b"foob").unpadded());
+    assert_contract(&Base64::new(*b"fooba").constant_time());
+}
+
+#[test]
+fn should_encode_base64_in_constant_time() {
+    let mut expected = Buffer::<4>::new();
+    let mut actual = Buffer::<4>::new();
+    for alphabet in [Base64Alphabet::Standard, Base64Alphabet::UrlSafe] {
+        for byte in 0..=u8::MAX {
+            let value = Base64::new([byte, byte ^ 0x55, !byte]).with_alphabet(alphabet);
+            assert_eq!(actual.format(value.constant_time()), expected.format(value));
+        }
+    }
+
+    let secret: [u8; 64] = core::array::from_fn(|idx| (idx * 73 + 11) as u8);
+    let value = Base64::new(secret).with_alphabet(Base64Alphabet::UrlSafe).unpadded();
+    let mut expected = Buffer::<{ <Base64<[u8; 64]>>::TEXT_SIZE }>::new();
+    let mut actual = Buffer::<{ <Base64<[u8; 64]>>::TEXT_SIZE }>::new();
+    assert_eq!(actual.format(value.constant_time()), expected.format(value));
+    assert_contract(&value.constant_time());
+}
+
+#[test]
+fn should_encode_base32() {
+    assert_eq!(<Base32<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<Base32<[u8; 1]>>::TEXT_SIZE, 8);
+    assert_eq!(<Base32<[u8; 5]>>::TEXT_SIZE, 8);
+    assert_eq!(<Base32<[u8; 20]>>::TEXT_SIZE, 32);
+
+    let mut buffer = Buffer::<16>::new();
+    //RFC 4648 test vectors
+    assert_eq!(buffer.format(Base32::new(*b"")), "");
+    assert_eq!(buffer.format(Base32::new(*b"f")), "MY======");
+    assert_eq!(buffer.format(Base32::new(*b"fo")), "MZXQ====");
+    assert_eq!(buffer.format(Base32::new(*b"foo")), "MZXW6===");
+    assert_eq!(buffer.format(Base32::new(*b"foob")), "MZXW6YQ=");
+    assert_eq!(buffer.format(Base32::new(*b"fooba")), "MZXW6YTB");
+    assert_eq!(buffer.format(Base32::new(*b"foobar")), "MZXW6YTBOI======");
+
+    let hex = Base32Alphabet::Hex;
+    assert_eq!(buffer.format(Base32::new(*b"f").with_alphabet(hex)), "CO======");
+    assert_eq!(buffer.format(Base32::new(*b"fo").with_alphabet(hex)), "CPNG====");
+    assert_eq!(buffer.format(Base32::new(*b"foo").with_alphabet(hex)), "CPNMU===");
+    assert_eq!(buffer.format(Base32::new(*b"foob").with_alphabet(hex)), "CPNMUOG=");
+    assert_eq!(buffer.format(Base32::new(*b"fooba").with_alphabet(hex)), "CPNMUOJ1");
+    assert_eq!(buffer.format(Base32::new(*b"foobar").with_alphabet(hex)), "CPNMUOJ1E8======");
+
+    assert_eq!(buffer.format(Base32::new(*b"foobar").unpadded()), "MZXW6YTBOI");
+    assert_eq!(buffer.format(Base32::new(*b"foobar").with_alphabet(hex).with_case(Case::Lower).unpadded()), "cpnmuoj1e8");
+    assert_eq!(buffer.format(Base32::new([0xff; 5]).with_case(Case::Lower)), "77777777");
+
+    assert_eq!(Base32::new(*b"foob").unpadded().text_len(), 7);
+    assert_eq!(Base32::new(*b"foob").try_to_str(&mut [0u8; 7]), Err(Error::BufferTooSmall { required: 8, available: 7 }));
+
+    for len in 0..=6 {
+        let bytes = *b"foobar";
+        let mut bytes = bytes.map(|byte| byte ^ len);
+        bytes[len as usize..].fill(0);
+        assert_contract(&Base32::new(bytes));
+        assert_contract(&Base32::new(bytes).with_alphabet(hex).unpadded());
+    }
+}
+
+//Reference implementation through repeated division by 58
+fn base58(bytes: &[u8]) -> String {
+    const DIGITS: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut num = bytes.to_vec();
+    let mut text = Vec::new();
+    while num.iter().any(|byte| *byte != 0) {
+        let mut rem = 0u32;
+        for byte in num.iter_mut() {
+            let acc = (rem << 8) | *byte as u32;
+            *byte = (acc / 58) as u8;
+            rem = acc % 58;
+        }
+        text.push(DIGITS[rem as usize]);
+    }
+    text.extend(core::iter::repeat_n(b'1', zeros));
+    text.reverse();
+    String::from_utf8(text).unwrap()
+}
+
+#[test]
+fn should_encode_base58() {
+    assert_eq!(<Base58<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<Base58<[u8; 1]>>::TEXT_SIZE, 2);
+    assert_eq!(<Base58<[u8; 25]>>::TEXT_SIZE, 35);
+    assert_eq!(<Base58<[u8; 32]>>::TEXT_SIZE, 45);
+
+    let mut buffer = Buffer::<{ <Base58<[u8; 44]>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(Base58::new(*b"")), "");
+    assert_eq!(buffer.format(Base58::new(*b"Hello World!")), "2NEpo7TZRRrLZSi2U");
+    assert_eq!(buffer.format(Base58::new(*b"The quick brown fox jumps over the lazy dog.")), "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z");
+    assert_eq!(buffer.format(Base58::new([0, 0, 0x28, 0x7f, 0xb4, 0xcd])), "11233QC4");
+    assert_eq!(buffer.format(Base58::new([0; 4])), "1111");
+    assert_eq!(buffer.format(Base58::new([57])), "z");
+    assert_eq!(buffer.format(Base58::new([58])), "21");
+
+    assert_eq!(Base58::new(*b"Hello World!").text_len(), 17);
+    assert_eq!(Base58::new(*b"Hello World!").try_to_str(&mut [0u8; 16]), Err(Error::BufferTooSmall { required: 17, available: 16 }));
+
+    for seed in 0..=u8::MAX {
+        let bytes: [u8; 32] = core::array::from_fn(|idx| match idx < (seed % 8) as usize {
+            true => 0,
+            false => seed.wrapping_mul(idx as u8).wrapping_add(seed),
+        });
+        assert_eq!(buffer.format(Base58::new(bytes)), base58(&bytes));
+        assert_eq!(buffer.format(Base58::new([seed, !seed, seed])), base58(&[seed, !seed, seed]));
+        assert_contract(&Base58::new(bytes));
+    }
+    assert_eq!(buffer.format(Base58::new([0xff; 32])).len(), 44);
+    assert_contract(&Base58::new([0xffu8; 32]));
+    assert_contract(&Base58::new([0u8; 32]));
+    assert_contract(&Base58::new(*b""));
+}
+
+#[test]
+fn should_encode_z85() {
+    assert_eq!(<Z85<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<Z85<[u8; 4]>>::TEXT_SIZE, 5);
+    assert_eq!(<Z85<[u8; 32]>>::TEXT_SIZE, 40);
+
+    let mut buffer = Buffer::<{ <Z85<[u8; 32]>>::TEXT_SIZE }>::new();
+    //ZeroMQ RFC 32 test vector
+    assert_eq!(buffer.format(Z85::new([0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b])), "HelloWorld");
+    assert_eq!(buffer.format(Z85::new([0; 4])), "00000");
+    assert_eq!(buffer.format(Z85::new([0xff; 4])), "%nSc0");
+
+    //Curve key pair from ZeroMQ RFC 32
+    let key = [
+        0xbb, 0x88, 0x47, 0x1d, 0x65, 0xe2, 0x65, 0x9b, 0x30, 0xc5, 0x5a, 0x53, 0x21, 0xce, 0xbb, 0x5a,
+        0xab, 0x2b, 0x70, 0xa3, 0x98, 0x64, 0x5c, 0x26, 0xdc, 0xa2, 0xb2, 0xfc, 0xb4, 0x3f, 0xc5, 0x18,
+    ];
+    assert_eq!(buffer.format(Z85::new(key)), "Yne@$w-vo<fVvi]a<NY6T1ed:M$fCG*[IaLV{hID");
+
+    assert_eq!(Z85::new(key).try_to_str(&mut [0u8; 39]), Err(Error::BufferTooSmall { required: 40, available: 39 }));
+    assert_contract(&Z85::new(key));
+    assert_contract(&Z85::new([0u8; 0]));
+}
+
+//Reference percent-encoding, with `extra` characters to encode besides controls, space, `%` and non-ASCII
+fn percent(bytes: &[u8], extra: &str, form: bool) -> String {
+    let mut text = String::new();
+    for byte in bytes {
+        if form && *byte == b' ' {
+            text.push('+');
+        } else if *byte <= b' ' || *byte >= 0x7f || *byte == b'%' || extra.as_bytes().contains(byte) {
+            text.push_str(&format!("%{byte:02X}"));
+        } else {
+            text.push(*byte as char);
+        }
+    }
+    text
+}
+
+#[test]
+fn should_percent_encode() {
+    assert_eq!(<PercentEncoded<[u8; 0]>>::TEXT_SIZE, 0);
+    assert_eq!(<PercentEncoded<[u8; 8]>>::TEXT_SIZE, 24);
+    assert_eq!(<PercentEncoded<FmtBuffer<8>>>::TEXT_SIZE, 24);
+    assert_eq!(<PercentEncoded<Buffer<8>>>::TEXT_SIZE, 24);
+
+    let mut buffer = Buffer::<{ <PercentEncoded<[u8; 16]>>::TEXT_SIZE }>::new();
+    let value = PercentEncoded::new(*b"a b/c?d&e=f+g%h");
+    assert_eq!(buffer.format(value), "a%20b%2Fc%3Fd%26e%3Df%2Bg%25h");
+    assert_eq!(buffer.format(value.with_set(EncodeSet::Form)), "a+b%2Fc%3Fd%26e%3Df%2Bg%25h");
+    assert_eq!(buffer.format(value.with_set(EncodeSet::Path)), "a%20b/c%3Fd&e=f+g%25h");
+    assert_eq!(buffer.format(value.with_set(EncodeSet::Query)), "a%20b/c?d&e=f+g%25h");
+    assert_eq!(buffer.format(value.with_set(EncodeSet::Userinfo)), "a%20b%2Fc%3Fd&e%3Df+g%25h");
+    assert_eq!(buffer.format(PercentEncoded::new(*b"-._~*!")), "-._~*!");
+    assert_eq!(buffer.format(PercentEncoded::new(*b"-._~*!").with_set(EncodeSet::Form)), "-._%7E*%21");
+    assert_eq!(buffer.format(PercentEncoded::new("€\0\x7f".as_bytes().try_into().unwrap_or([0u8; 5]))), "%E2%82%AC%00%7F");
+
+    let all: [u8; 256] = core::array::from_fn(|idx| idx as u8);
+    let mut buffer = Buffer::<{ <PercentEncoded<[u8; 256]>>::TEXT_SIZE }>::new();
+    for (set, extra) in [
+        (EncodeSet::Query, "\"#<>"),
+        (EncodeSet::Path, "\"#<>?^`{}"),
+        (EncodeSet::Userinfo, "\"#<>?^`{}/:;=@[\\]|"),
+        (EncodeSet::Component, "\"#<>?^`{}/:;=@[\\]|$&+,"),
+        (EncodeSet::Form, "\"#<>?^`{}/:;=@[\\]|$&+,!'()~"),
+    ] {
+        let value = PercentEncoded::new(all).with_set(set);
+        assert_eq!(buffer.format(value), percent(&all, extra, set == EncodeSet::Form));
+        assert_contract(&value);
+    }
+
+    assert_eq!(PercentEncoded::new(*b"a b").text_len(), 5);
+    assert_eq!(PercentEncoded::new(*b"a b").try_to_str(&mut [0u8; 4]), Err(Error::BufferTooSmall { required: 5, available: 4 }));
+    assert_contract(&PercentEncoded::new(*b""));
+}
+
+#[test]
+fn should_percent_encode_text_buffers() {
+    let capture = || FmtBuffer::<8>::capture(format_args!("{} {}", "key", 1));
+
+    let mut buffer = Buffer::<{ <PercentEncoded<FmtBuffer<8>>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(PercentEncoded::new(capture())), "key%201");
+    assert_eq!(buffer.format(PercentEncoded::new(capture()).with_set(EncodeSet::Form)), "key+1");
+    assert_contract(&PercentEncoded::new(capture()).with_set(EncodeSet::Form));
+
+    let number = || Buffer::<{ i64::TEXT_SIZE }>::fmt(-10i64);
+    let mut buffer = Buffer::<{ <PercentEncoded<Buffer<{ i64::TEXT_SIZE }>>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(PercentEncoded::new(number())), "-10");
+    assert_contract(&PercentEncoded::new(number()));
+    assert_contract(&PercentEncoded::new(Buffer::<4>::new()));
+}
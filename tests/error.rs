@@ -0,0 +1,78 @@
+use to_str::{Buffer, Buffer64, Error, ToStr};
+
+use core::num;
+
+#[test]
+fn should_report_insufficient_buffer_for_integers() {
+    let mut buffer = [0u8; 3];
+
+    assert_eq!(255u8.try_to_str(&mut buffer), Ok("255"));
+    assert_eq!(1000u16.try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 4, available: 3 }));
+    assert_eq!(u128::MAX.try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 39, available: 3 }));
+    assert_eq!((-10i64).try_to_str(&mut buffer), Ok("-10"));
+    assert_eq!((-100i64).try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 4, available: 3 }));
+    assert_eq!(i128::MIN.try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 40, available: 3 }));
+    assert_eq!((-99isize).try_to_str(&mut buffer), Ok("-99"));
+    assert_eq!(999usize.try_to_str(&mut buffer), Ok("999"));
+    assert_eq!(num::NonZeroU32::MAX.try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 10, available: 3 }));
+    assert_eq!(<&u64 as ToStr>::try_to_str(&&5, &mut buffer), Ok("5"));
+    assert_eq!(0u64.try_to_str(&mut []), Err(Error::BufferTooSmall { required: 1, available: 0 }));
+
+    let mut buffer = [0u8; 40];
+    for len in 0..buffer.len() {
+        let buffer = &mut buffer[..len];
+        let expected = i128::MIN.to_string();
+        match i128::MIN.try_to_str(buffer) {
+            Ok(text) => assert_eq!(text, expected),
+            Err(error) => assert_eq!(error, Error::BufferTooSmall { required: expected.len(), available: len }),
+        }
+
+        let expected = u128::MAX.to_string();
+        match u128::MAX.try_to_str(buffer) {
+            Ok(text) => assert_eq!(text, expected),
+            Err(error) => assert_eq!(error, Error::BufferTooSmall { required: expected.len(), available: len }),
+        }
+    }
+}
+
+#[test]
+fn should_report_insufficient_buffer_for_pointers() {
+    let ptr = 0xabcusize as *const u8;
+    let mut buffer = [0u8; 5];
+    assert_eq!(ptr.try_to_str(&mut buffer), Ok("0xabc"));
+    assert_eq!((ptr as *mut u8).try_to_str(&mut buffer[1..]), Err(Error::BufferTooSmall { required: 5, available: 4 }));
+}
+
+#[test]
+fn should_use_default_try_to_str() {
+    struct Text(&'static str);
+
+    unsafe impl ToStr for Text {
+        const TEXT_SIZE: usize = 8;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            assert!(buffer.len() >= Self::TEXT_SIZE);
+            let offset = buffer.len() - self.0.len();
+            buffer[offset..].copy_from_slice(self.0.as_bytes());
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    let mut buffer = [0u8; 4];
+    assert_eq!(Text("text").try_to_str(&mut buffer), Ok("text"));
+    assert_eq!(Text("ab").try_to_str(&mut buffer), Ok("ab"));
+    assert_eq!(Text("longer").try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 6, available: 4 }));
+    assert_eq!(Error::BufferTooSmall { required: 6, available: 4 }.to_string(), "Buffer too small: requires 6 bytes, but only 4 available");
+}
+
+#[test]
+fn should_try_write_buffer() {
+    let mut buffer = Buffer::<3>::new();
+    assert_eq!(buffer.try_write(255u32), Ok("255"));
+    assert_eq!(buffer.try_write(1000u32), Err(Error::BufferTooSmall { required: 4, available: 3 }));
+    assert_eq!(buffer.as_str(), "255");
+    assert_eq!(buffer.try_format(-1i8), Ok("-1"));
+
+    let mut buffer = Buffer64::new();
+    assert_eq!(buffer.try_write(u64::MAX), Ok(u64::MAX.to_string().as_str()));
+}
@@ -0,0 +1,84 @@
+use to_str::{Buffer, Error, ToStr};
+use to_str::escape::{CodePoint, EscapeDebug, EscapeUnicode, HtmlDecimal, HtmlHex, JsonEscape};
+use to_str::testing::assert_contract;
+
+const CHARS: [char; 12] = ['a', '\0', '\n', '\'', '"', '\\', 'é', '€', '\u{301}', '\u{ffff}', '😀', char::MAX];
+
+#[test]
+fn should_escape_debug() {
+    assert_eq!(EscapeDebug::TEXT_SIZE, 10);
+
+    let mut buffer = [0u8; EscapeDebug::TEXT_SIZE];
+    for ch in CHARS {
+        assert_eq!(EscapeDebug(ch).to_str(&mut buffer), ch.escape_debug().to_string());
+        assert_contract(&EscapeDebug(ch));
+    }
+    assert_eq!(EscapeDebug(char::MAX).to_str(&mut buffer), "\\u{10ffff}");
+}
+
+#[test]
+fn should_format_code_point() {
+    assert_eq!(CodePoint::TEXT_SIZE, 8);
+
+    let mut buffer = Buffer::<{ CodePoint::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(CodePoint('a')), "U+0061");
+    assert_eq!(buffer.format(CodePoint('\0')), "U+0000");
+    assert_eq!(buffer.format(CodePoint('€')), "U+20AC");
+    assert_eq!(buffer.format(CodePoint('😀')), "U+1F600");
+    assert_eq!(buffer.format(CodePoint(char::MAX)), "U+10FFFF");
+
+    for ch in CHARS {
+        assert_contract(&CodePoint(ch));
+    }
+}
+
+#[test]
+fn should_format_unicode_escape() {
+    assert_eq!(EscapeUnicode::TEXT_SIZE, 10);
+
+    let mut buffer = [0u8; EscapeUnicode::TEXT_SIZE];
+    for ch in CHARS {
+        assert_eq!(EscapeUnicode(ch).to_str(&mut buffer), ch.escape_unicode().to_string().to_uppercase().replace("\\U", "\\u"));
+        assert_contract(&EscapeUnicode(ch));
+    }
+    assert_eq!(EscapeUnicode('\0').to_str(&mut buffer), "\\u{0}");
+    assert_eq!(EscapeUnicode('😀').to_str(&mut buffer), "\\u{1F600}");
+}
+
+#[test]
+fn should_format_html_references() {
+    assert_eq!(HtmlHex::TEXT_SIZE, 10);
+    assert_eq!(HtmlDecimal::TEXT_SIZE, 10);
+
+    let mut buffer = [0u8; 10];
+    assert_eq!(HtmlHex('<').to_str(&mut buffer), "&#x3C;");
+    assert_eq!(HtmlHex(char::MAX).to_str(&mut buffer), "&#x10FFFF;");
+    assert_eq!(HtmlDecimal('<').to_str(&mut buffer), "&#60;");
+    assert_eq!(HtmlDecimal('\0').to_str(&mut buffer), "&#0;");
+    assert_eq!(HtmlDecimal(char::MAX).to_str(&mut buffer), "&#1114111;");
+    assert_eq!(HtmlDecimal('€').try_to_str(&mut buffer[..6]), Err(Error::BufferTooSmall { required: 7, available: 6 }));
+
+    for ch in CHARS {
+        assert_eq!(HtmlDecimal(ch).to_str(&mut buffer), format!("&#{};", ch as u32));
+        assert_eq!(HtmlHex(ch).to_str(&mut buffer), format!("&#x{:X};", ch as u32));
+        assert_contract(&HtmlHex(ch));
+        assert_contract(&HtmlDecimal(ch));
+    }
+}
+
+#[test]
+fn should_format_json_escape() {
+    assert_eq!(JsonEscape::TEXT_SIZE, 12);
+
+    let mut buffer = [0u8; JsonEscape::TEXT_SIZE];
+    assert_eq!(JsonEscape('\0').to_str(&mut buffer), "\\u0000");
+    assert_eq!(JsonEscape('é').to_str(&mut buffer), "\\u00e9");
+    assert_eq!(JsonEscape('\u{ffff}').to_str(&mut buffer), "\\uffff");
+    assert_eq!(JsonEscape('😀').to_str(&mut buffer), "\\ud83d\\ude00");
+    assert_eq!(JsonEscape(char::MAX).to_str(&mut buffer), "\\udbff\\udfff");
+    assert_eq!(JsonEscape('😀').try_to_str(&mut buffer[..11]), Err(Error::BufferTooSmall { required: 12, available: 11 }));
+
+    for ch in CHARS {
+        assert_contract(&JsonEscape(ch));
+    }
+}
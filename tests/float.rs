@@ -0,0 +1,70 @@
+use to_str::{Buffer, ToStr};
+use to_str::testing::assert_contract;
+
+//Expected text as per documented layout
+fn expected<T: core::fmt::Display + core::fmt::LowerExp + Copy + Into<f64>>(value: T) -> String {
+    let abs = value.into().abs();
+    if abs == 0.0 || (1e-5..1e17).contains(&abs) || !abs.is_finite() {
+        format!("{value}")
+    } else {
+        format!("{value:e}")
+    }
+}
+
+#[test]
+fn should_format_f64() {
+    assert_eq!(f64::TEXT_SIZE, 24);
+
+    let mut buffer = Buffer::<{ f64::TEXT_SIZE }>::new();
+    for (value, text) in [
+        (0.0, "0"), (-0.0, "-0"), (1.0, "1"), (-1.5, "-1.5"), (0.1, "0.1"), (1e-5, "0.00001"), (9.9e-6, "9.9e-6"),
+        (1e16, "10000000000000000"), (1e17, "1e17"), (123456.789, "123456.789"), (f64::MAX, "1.7976931348623157e308"),
+        (f64::MIN_POSITIVE, "2.2250738585072014e-308"), (-5e-324, "-5e-324"), (f64::NAN, "NaN"), (f64::INFINITY, "inf"), (f64::NEG_INFINITY, "-inf"),
+        (-1.2345678901234567e-5, "-0.000012345678901234568"),
+    ] {
+        assert_eq!(buffer.format(value), text);
+        assert_eq!(value.text_len(), text.len());
+        assert_contract(&value);
+    }
+
+    let mut bits = 0x9e37_79b9_7f4a_7c15u64;
+    let mut max_len = 0;
+    for _ in 0..100_000 {
+        bits = bits.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let value = f64::from_bits(bits);
+        let text = buffer.format(value);
+        assert_eq!(text, expected(value));
+        if !value.is_nan() {
+            assert_eq!(text.parse::<f64>().unwrap().to_bits(), value.to_bits());
+        }
+        max_len = max_len.max(text.len());
+
+        //Cover decimal layout, which is rare among random bits
+        let value = value.abs() % 1e17 * if bits & 1 == 0 { 1.0 } else { -1e-21 };
+        assert_eq!(buffer.format(value), expected(value));
+        max_len = max_len.max(value.text_len());
+    }
+    assert_eq!(max_len, f64::TEXT_SIZE);
+}
+
+#[test]
+fn should_format_f32() {
+    assert_eq!(f32::TEXT_SIZE, 18);
+
+    let mut buffer = Buffer::<{ f32::TEXT_SIZE }>::new();
+    for (value, text) in [(0.0f32, "0"), (-2.5, "-2.5"), (1e-5, "0.00001"), (1e16, "10000000000000000"), (-1e17, "-1e17"), (f32::MAX, "3.4028235e38"), (f32::NAN, "NaN")] {
+        assert_eq!(buffer.format(value), text);
+        assert_eq!(value.text_len(), text.len());
+        assert_contract(&value);
+    }
+
+    let mut max_len = 0;
+    for bits in (0..=u32::MAX).step_by(4093) {
+        let value = f32::from_bits(bits);
+        let text = buffer.format(value);
+        assert_eq!(text, expected(value));
+        max_len = max_len.max(text.len());
+    }
+    assert_eq!(buffer.format(-1.2345678e16f32), "-12345678000000000");
+    assert_eq!(max_len.max("-12345678000000000".len()), f32::TEXT_SIZE);
+}
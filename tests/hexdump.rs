@@ -0,0 +1,65 @@
+use to_str::{Buffer, Error, ToStr};
+use to_str::hexdump::{HexDump, HexDumpLine};
+use to_str::testing::assert_contract;
+
+//Reference implementation of `hexdump -C` line
+fn line(offset: usize, bytes: &[u8]) -> String {
+    let mut hex = String::new();
+    for (idx, byte) in bytes.iter().enumerate() {
+        if idx == 8 {
+            hex.push(' ');
+        }
+        hex.push_str(&format!("{byte:02x} "));
+    }
+    let text: String = bytes.iter().map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' }).collect();
+    format!("{offset:08x}  {hex:<49} |{text}|")
+}
+
+#[test]
+fn should_format_hexdump_line() {
+    assert_eq!(HexDumpLine::TEXT_SIZE, usize::BITS as usize / 4 + 70);
+
+    let mut buffer = Buffer::<{ HexDumpLine::TEXT_SIZE }>::new();
+    let full = HexDumpLine::new(0x10, b"Hello world\n\0\0\0\0").unwrap();
+    assert_eq!(buffer.format(full), "00000010  48 65 6c 6c 6f 20 77 6f  72 6c 64 0a 00 00 00 00  |Hello world.....|");
+    assert_eq!(full.text_len(), 78);
+
+    let short = HexDumpLine::new(0, b"ab\n").unwrap();
+    assert_eq!(buffer.format(short), "00000000  61 62 0a                                          |ab.|");
+
+    let half = HexDumpLine::new(0x7fff_fff0, &[0x7e, 0x7f, 0x80, 0xff, 0x20, 0x1f, 0x00, 0x41]).unwrap();
+    assert_eq!(buffer.format(half), "7ffffff0  7e 7f 80 ff 20 1f 00 41                           |~... ..A|");
+
+    let empty = HexDumpLine::new(0, &[]).unwrap();
+    assert_eq!(buffer.format(empty), "00000000                                                    ||");
+
+    let wide = HexDumpLine::new(usize::MAX, &[0xff; 16]).unwrap();
+    assert_eq!(buffer.format(wide), line(usize::MAX, &[0xff; 16]));
+    assert_eq!(buffer.format(wide).len(), HexDumpLine::TEXT_SIZE);
+
+    assert!(HexDumpLine::new(0, &[0; 17]).is_none());
+    assert_eq!(short.offset(), 0);
+    assert_eq!(short.bytes(), b"ab\n");
+    assert_eq!(full.try_to_str(&mut [0u8; 77]), Err(Error::BufferTooSmall { required: 78, available: 77 }));
+
+    for value in [full, short, half, empty, wide] {
+        assert_contract(&value);
+    }
+}
+
+#[test]
+fn should_iterate_hexdump() {
+    let bytes: Vec<u8> = (0..=u8::MAX).chain(0..7).collect();
+    let mut dump = HexDump::new(&bytes).with_offset(0x1000);
+    assert_eq!(dump.len(), 17);
+
+    let mut buffer = Buffer::<{ HexDumpLine::TEXT_SIZE }>::new();
+    for (idx, chunk) in bytes.chunks(16).enumerate() {
+        let line_value = dump.next().unwrap();
+        assert_eq!(line_value.offset(), 0x1000 + idx * 16);
+        assert_eq!(buffer.format(line_value), line(0x1000 + idx * 16, chunk));
+        assert_contract(&line_value);
+    }
+    assert!(dump.next().is_none());
+    assert_eq!(HexDump::new(&[]).count(), 0);
+}
@@ -0,0 +1,116 @@
+use to_str::{Buffer, Case, Error, ParseError, ToStr};
+use to_str::id::{Braced, Hyphenated, Simple, Ulid, Urn, Uuid};
+use to_str::testing::assert_contract;
+
+const ID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+#[test]
+fn should_format_uuid() {
+    assert_eq!(Uuid::TEXT_SIZE, 36);
+    assert_eq!(Hyphenated::TEXT_SIZE, 36);
+    assert_eq!(Simple::TEXT_SIZE, 32);
+    assert_eq!(Urn::TEXT_SIZE, 45);
+    assert_eq!(Braced::TEXT_SIZE, 38);
+
+    let mut buffer = Buffer::<{ Urn::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(ID), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(buffer.format(ID.simple()), "67e5504410b1426f9247bb680e5fe0c8");
+    assert_eq!(buffer.format(ID.urn()), "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(buffer.format(ID.braced()), "{67e55044-10b1-426f-9247-bb680e5fe0c8}");
+    assert_eq!(buffer.format(ID.hyphenated().with_case(Case::Upper)), "67E55044-10B1-426F-9247-BB680E5FE0C8");
+    assert_eq!(buffer.format(ID.simple().with_case(Case::Upper)), "67E5504410B1426F9247BB680E5FE0C8");
+    assert_eq!(buffer.format(Uuid::NIL), "00000000-0000-0000-0000-000000000000");
+    assert_eq!(buffer.format(Uuid::MAX.braced()), "{ffffffff-ffff-ffff-ffff-ffffffffffff}");
+
+    assert_eq!(ID.try_to_str(&mut [0u8; 35]), Err(Error::BufferTooSmall { required: 36, available: 35 }));
+
+    for uuid in [ID, Uuid::NIL, Uuid::MAX] {
+        for case in [Case::Lower, Case::Upper] {
+            assert_contract(&uuid.hyphenated().with_case(case));
+            assert_contract(&uuid.simple().with_case(case));
+            assert_contract(&uuid.urn().with_case(case));
+            assert_contract(&uuid.braced().with_case(case));
+        }
+        assert_contract(&uuid);
+    }
+}
+
+#[test]
+fn should_convert_uuid_bytes() {
+    const BYTES: [u8; 16] = [0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8];
+
+    assert_eq!(Uuid::from_bytes(BYTES), ID);
+    assert_eq!(ID.to_bytes(), BYTES);
+    assert_eq!(Uuid::from(ID.as_u128()), ID);
+}
+
+#[test]
+fn should_parse_uuid() {
+    const PARSED: Result<Uuid, ParseError> = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(PARSED, Ok(ID));
+
+    let mut buffer = Buffer::<{ Urn::TEXT_SIZE }>::new();
+    for case in [Case::Lower, Case::Upper] {
+        assert_eq!(Uuid::parse_str(buffer.format(ID.hyphenated().with_case(case))), Ok(ID));
+        assert_eq!(Uuid::parse_str(buffer.format(ID.simple().with_case(case))), Ok(ID));
+        assert_eq!(Uuid::parse_str(buffer.format(ID.urn().with_case(case))), Ok(ID));
+        assert_eq!(Uuid::parse_str(buffer.format(ID.braced().with_case(case))), Ok(ID));
+    }
+    assert_eq!("ffffffffffffffffffffffffffffffff".parse::<Uuid>(), Ok(Uuid::MAX));
+    assert_eq!(Uuid::parse_str("URN:UUID:67E55044-10B1-426F-9247-BB680E5FE0C8"), Ok(ID));
+    assert_eq!(Uuid::parse_str("Urn:Uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"), Ok(ID));
+
+    assert_eq!(Uuid::parse_str(""), Err(ParseError::InvalidLength { len: 0 }));
+    assert_eq!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c"), Err(ParseError::InvalidLength { len: 35 }));
+    assert_eq!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0cg"), Err(ParseError::InvalidCharacter { index: 35 }));
+    assert_eq!(Uuid::parse_str("67e55044_10b1-426f-9247-bb680e5fe0c8"), Err(ParseError::InvalidCharacter { index: 8 }));
+    assert_eq!(Uuid::parse_str("67e5504410b1-426f-9247-bb680e5fe0c8-"), Err(ParseError::InvalidCharacter { index: 8 }));
+    assert_eq!(Uuid::parse_str("(67e55044-10b1-426f-9247-bb680e5fe0c8}"), Err(ParseError::InvalidCharacter { index: 0 }));
+    assert_eq!(Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c8)"), Err(ParseError::InvalidCharacter { index: 37 }));
+    assert_eq!(Uuid::parse_str("urn:uid:-67e55044-10b1-426f-9247-bb680e5fe0c8"), Err(ParseError::InvalidCharacter { index: 5 }));
+    assert_eq!(Uuid::parse_str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cX"), Err(ParseError::InvalidCharacter { index: 44 }));
+}
+
+#[test]
+fn should_format_ulid() {
+    assert_eq!(Ulid::TEXT_SIZE, 26);
+
+    let id = Ulid::from_parts(1469922850259, 0xd676_4c61_efb9_9302_bd5b);
+    assert_eq!(id.timestamp_ms(), 1469922850259);
+    assert_eq!(id.random(), 0xd676_4c61_efb9_9302_bd5b);
+    assert_eq!(Ulid::from_parts(u64::MAX, u128::MAX), Ulid::from_u128(u128::MAX));
+
+    let mut buffer = Buffer::<{ Ulid::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(id), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    assert_eq!(buffer.format(Ulid::from_u128(0)), "00000000000000000000000000");
+    assert_eq!(buffer.format(Ulid::from_u128(u128::MAX)), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    assert_eq!(id.try_to_str(&mut [0u8; 25]), Err(Error::BufferTooSmall { required: 26, available: 25 }));
+
+    for id in [id, Ulid::from_u128(0), Ulid::from_u128(u128::MAX)] {
+        assert_eq!(Ulid::parse_str(buffer.format(id)), Ok(id));
+        assert_contract(&id);
+    }
+
+    //Text order matches numeric order
+    let mut left = Buffer::<{ Ulid::TEXT_SIZE }>::new();
+    let mut right = Buffer::<{ Ulid::TEXT_SIZE }>::new();
+    for (a, b) in [(0, 1), (31, 32), (id.as_u128(), id.as_u128() + 1), (1 << 80, (1 << 81) - 1)] {
+        assert!(left.format(Ulid::from_u128(a)) < right.format(Ulid::from_u128(b)));
+    }
+}
+
+#[test]
+fn should_parse_ulid() {
+    const PARSED: Result<Ulid, ParseError> = Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    assert_eq!(PARSED.map(|id| id.timestamp_ms()), Ok(1469922850259));
+
+    assert_eq!("01arz3ndektsv4rrffq69g5fav".parse::<Ulid>(), PARSED);
+    assert_eq!(Ulid::parse_str("OIL00000000000000000000000"), Ulid::parse_str("01100000000000000000000000"));
+    assert_eq!(Ulid::parse_str("oil00000000000000000000000"), Ulid::parse_str("01100000000000000000000000"));
+
+    assert_eq!(Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FA"), Err(ParseError::InvalidLength { len: 25 }));
+    assert_eq!(Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAVV"), Err(ParseError::InvalidLength { len: 27 }));
+    assert_eq!(Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAU"), Err(ParseError::InvalidCharacter { index: 25 }));
+    assert_eq!(Ulid::parse_str("01ARZ3NDEKTSV4RR-FQ69G5FAV"), Err(ParseError::InvalidCharacter { index: 16 }));
+    assert_eq!(Ulid::parse_str("80000000000000000000000000"), Err(ParseError::Overflow));
+}
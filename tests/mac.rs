@@ -0,0 +1,44 @@
+use to_str::{Buffer, Case, Error, ToStr};
+use to_str::mac::{Eui64, MacAddr, MacStyle};
+use to_str::testing::assert_contract;
+
+const STYLES: [MacStyle; 4] = [MacStyle::Colon, MacStyle::Hyphen, MacStyle::Dotted, MacStyle::Bare];
+
+#[test]
+fn should_format_mac_addr() {
+    assert_eq!(MacAddr::TEXT_SIZE, 17);
+
+    let addr = MacAddr::new([0x00, 0x1a, 0x2b, 0xc3, 0xd4, 0xff]);
+    let mut buffer = Buffer::<{ MacAddr::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(addr), "00:1a:2b:c3:d4:ff");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Hyphen)), "00-1a-2b-c3-d4-ff");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Dotted)), "001a.2bc3.d4ff");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Bare)), "001a2bc3d4ff");
+    assert_eq!(buffer.format(addr.with_case(Case::Upper)), "00:1A:2B:C3:D4:FF");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Dotted).with_case(Case::Upper)), "001A.2BC3.D4FF");
+
+    assert_eq!(addr.with_style(MacStyle::Dotted).text_len(), 14);
+    assert_eq!(addr.try_to_str(&mut [0u8; 16]), Err(Error::BufferTooSmall { required: 17, available: 16 }));
+
+    for style in STYLES {
+        for case in [Case::Lower, Case::Upper] {
+            assert_contract(&addr.with_style(style).with_case(case));
+        }
+    }
+}
+
+#[test]
+fn should_format_eui64() {
+    assert_eq!(Eui64::TEXT_SIZE, 23);
+
+    let addr = Eui64::from([0x02, 0x00, 0x5e, 0x10, 0x00, 0x00, 0x00, 0x01]);
+    let mut buffer = Buffer::<{ Eui64::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(addr), "02:00:5e:10:00:00:00:01");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Hyphen)), "02-00-5e-10-00-00-00-01");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Dotted)), "0200.5e10.0000.0001");
+    assert_eq!(buffer.format(addr.with_style(MacStyle::Bare).with_case(Case::Upper)), "02005E1000000001");
+
+    for style in STYLES {
+        assert_contract(&addr.with_style(style));
+    }
+}
@@ -0,0 +1,116 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use to_str::{Buffer, Error, ToStr};
+use to_str::net::Cidr;
+use to_str::testing::assert_contract;
+
+const IPV6: [&str; 16] = [
+    "::",
+    "::1",
+    "1::",
+    "::ffff:0.0.0.0",
+    "::ffff:192.168.1.1",
+    "::192.168.1.1",
+    "64:ff9b::1.2.3.4",
+    "2001:db8::1",
+    "2001:db8:0:1:1:1:1:1",
+    "2001:0:0:1::1",
+    "2001:db8::1:0:0:1",
+    "1:0:0:2:0:0:0:3",
+    "fe80::1:2:3:4",
+    "1:2:3:4:5:6:7:8",
+    "1:2:3:4:5:6:7::",
+    "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+];
+
+#[test]
+fn should_format_ipv4() {
+    assert_eq!(Ipv4Addr::TEXT_SIZE, 15);
+
+    let mut buffer = Buffer::<{ Ipv4Addr::TEXT_SIZE }>::new();
+    for addr in [Ipv4Addr::UNSPECIFIED, Ipv4Addr::LOCALHOST, Ipv4Addr::BROADCAST, Ipv4Addr::new(10, 20, 100, 9)] {
+        assert_eq!(buffer.format(addr), addr.to_string());
+        assert_contract(&addr);
+    }
+    assert_eq!(Ipv4Addr::LOCALHOST.try_to_str(&mut [0u8; 8]), Err(Error::BufferTooSmall { required: 9, available: 8 }));
+}
+
+#[test]
+fn should_format_ipv6() {
+    assert_eq!(Ipv6Addr::TEXT_SIZE, 39);
+    assert_eq!(IpAddr::TEXT_SIZE, 39);
+
+    let mut buffer = Buffer::<{ Ipv6Addr::TEXT_SIZE }>::new();
+    for text in IPV6 {
+        let addr: Ipv6Addr = text.parse().unwrap();
+        assert_eq!(buffer.format(addr), addr.to_string());
+        assert_eq!(buffer.format(IpAddr::V6(addr)), addr.to_string());
+        assert_contract(&addr);
+        assert_contract(&IpAddr::V6(addr));
+    }
+
+    assert_eq!(buffer.format(Ipv6Addr::LOCALHOST), "::1");
+    assert_eq!(buffer.format("2001:db8:0:0:1:0:0:1".parse::<Ipv6Addr>().unwrap()), "2001:db8::1:0:0:1");
+    assert_eq!(buffer.format(Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped()), "::ffff:1.2.3.4");
+    assert_eq!(buffer.format(IpAddr::V4(Ipv4Addr::BROADCAST)), "255.255.255.255");
+}
+
+#[test]
+fn should_format_socket_addr() {
+    assert_eq!(SocketAddrV4::TEXT_SIZE, 21);
+    assert_eq!(SocketAddrV6::TEXT_SIZE, 58);
+    assert_eq!(SocketAddr::TEXT_SIZE, 58);
+
+    let mut buffer = Buffer::<{ SocketAddr::TEXT_SIZE }>::new();
+    let addr = SocketAddrV4::new(Ipv4Addr::BROADCAST, u16::MAX);
+    assert_eq!(buffer.format(addr), "255.255.255.255:65535");
+    assert_contract(&addr);
+
+    let addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0);
+    assert_eq!(buffer.format(addr), "[::1]:443");
+    assert_eq!(buffer.format(SocketAddr::V6(addr)), "[::1]:443");
+
+    let addr = SocketAddrV6::new("fe80::1".parse().unwrap(), 0, 0, 3);
+    assert_eq!(buffer.format(addr), "[fe80::1%3]:0");
+
+    let addr = SocketAddrV6::new(Ipv6Addr::from_bits(u128::MAX), u16::MAX, 0, u32::MAX);
+    assert_eq!(buffer.format(addr).len(), SocketAddrV6::TEXT_SIZE);
+
+    for text in IPV6 {
+        for (port, scope_id) in [(0, 0), (80, 1), (u16::MAX, u32::MAX)] {
+            let addr = SocketAddr::V6(SocketAddrV6::new(text.parse().unwrap(), port, 0, scope_id));
+            assert_eq!(buffer.format(addr), addr.to_string());
+            assert_contract(&addr);
+        }
+    }
+    let addr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080));
+    assert_eq!(buffer.format(addr), addr.to_string());
+    assert_contract(&addr);
+}
+
+#[test]
+fn should_format_cidr() {
+    assert_eq!(<Cidr<Ipv4Addr>>::TEXT_SIZE, 18);
+    assert_eq!(<Cidr<Ipv6Addr>>::TEXT_SIZE, 43);
+    assert_eq!(<Cidr<IpAddr>>::TEXT_SIZE, 43);
+
+    assert!(Cidr::<Ipv4Addr>::new(Ipv4Addr::UNSPECIFIED, 33).is_none());
+    assert!(Cidr::<Ipv6Addr>::new(Ipv6Addr::UNSPECIFIED, 129).is_none());
+    assert!(Cidr::<IpAddr>::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 33).is_none());
+    assert!(Cidr::<IpAddr>::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 128).is_some());
+
+    let mut buffer = Buffer::<{ Cidr::<IpAddr>::TEXT_SIZE }>::new();
+    let cidr = Cidr::<Ipv4Addr>::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    assert_eq!(cidr.prefix(), 8);
+    assert_eq!(*cidr.addr(), Ipv4Addr::new(10, 0, 0, 0));
+    assert_eq!(buffer.format(cidr), "10.0.0.0/8");
+    assert_contract(&cidr);
+
+    let cidr = Cidr::<Ipv6Addr>::new("2001:db8::".parse().unwrap(), 32).unwrap();
+    assert_eq!(buffer.format(cidr), "2001:db8::/32");
+    assert_contract(&cidr);
+
+    let cidr = Cidr::<IpAddr>::new(IpAddr::V6(Ipv6Addr::from_bits(u128::MAX)), 128).unwrap();
+    assert_eq!(buffer.format(cidr).len(), Cidr::<IpAddr>::TEXT_SIZE);
+    assert_contract(&cidr);
+}
@@ -1,6 +1,7 @@
 use to_str::{ToStr, Buffer128, Buffer64, Buffer8};
 
 use core::num;
+use core::mem::MaybeUninit;
 use core::fmt::Write;
 
 const _: Buffer8 = Buffer8::fmt_u8(u8::MAX);
@@ -11,80 +12,108 @@ const _: Buffer128 = Buffer128::fmt_u128(u128::MAX);
 fn should_check_const_foramt() {
     let mut buffer = Buffer128::new();
 
-    let result = buffer.format_u8(u8::max_value());
+    let result = buffer.format_u8(u8::MAX);
     assert_eq!(result, "255");
-    assert_eq!(result, Buffer128::fmt_u8(u8::max_value()).as_str());
+    assert_eq!(result, Buffer128::fmt_u8(u8::MAX).as_str());
 
-    let result = buffer.format_u16(u16::max_value());
-    assert_eq!(result, u16::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_u16(u16::max_value()).as_str());
+    let result = buffer.format_u16(u16::MAX);
+    assert_eq!(result, u16::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_u16(u16::MAX).as_str());
 
-    let result = buffer.format_u32(u32::max_value());
-    assert_eq!(result, u32::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_u32(u32::max_value()).as_str());
+    let result = buffer.format_u32(u32::MAX);
+    assert_eq!(result, u32::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_u32(u32::MAX).as_str());
 
-    let result = buffer.format_u64(u64::max_value());
-    assert_eq!(result, u64::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_u64(u64::max_value()).as_str());
+    let result = buffer.format_u64(u64::MAX);
+    assert_eq!(result, u64::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_u64(u64::MAX).as_str());
 
-    let result = buffer.format_usize(usize::max_value());
-    assert_eq!(result, usize::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_usize(usize::max_value()).as_str());
+    let result = buffer.format_usize(usize::MAX);
+    assert_eq!(result, usize::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_usize(usize::MAX).as_str());
 
-    let result = buffer.format_u128(u128::max_value());
-    assert_eq!(result, u128::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_u128(u128::max_value()).as_str());
+    let result = buffer.format_u128(u128::MAX);
+    assert_eq!(result, u128::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_u128(u128::MAX).as_str());
 
-    let result = buffer.format_i8(i8::max_value());
+    let result = buffer.format_i8(i8::MAX);
     assert_eq!(result, "127");
-    assert_eq!(result, Buffer128::fmt_i8(i8::max_value()).as_str());
+    assert_eq!(result, Buffer128::fmt_i8(i8::MAX).as_str());
 
-    let result = buffer.format_i8(i8::min_value());
+    let result = buffer.format_i8(i8::MIN);
     assert_eq!(result, "-128");
-    assert_eq!(result, Buffer128::fmt_i8(i8::min_value()).as_str());
+    assert_eq!(result, Buffer128::fmt_i8(i8::MIN).as_str());
 
-    let result = buffer.format_i16(i16::max_value());
-    assert_eq!(result, i16::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i16(i16::max_value()).as_str());
+    let result = buffer.format_i16(i16::MAX);
+    assert_eq!(result, i16::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_i16(i16::MAX).as_str());
 
-    let result = buffer.format_i16(i16::min_value());
-    assert_eq!(result, i16::min_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i16(i16::min_value()).as_str());
+    let result = buffer.format_i16(i16::MIN);
+    assert_eq!(result, i16::MIN.to_string());
+    assert_eq!(result, Buffer128::fmt_i16(i16::MIN).as_str());
 
-    let result = buffer.format_i32(i32::max_value());
-    assert_eq!(result, i32::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i32(i32::max_value()).as_str());
+    let result = buffer.format_i32(i32::MAX);
+    assert_eq!(result, i32::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_i32(i32::MAX).as_str());
 
-    let result = buffer.format_i32(i32::min_value());
-    assert_eq!(result, i32::min_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i32(i32::min_value()).as_str());
+    let result = buffer.format_i32(i32::MIN);
+    assert_eq!(result, i32::MIN.to_string());
+    assert_eq!(result, Buffer128::fmt_i32(i32::MIN).as_str());
 
-    let result = buffer.format_i64(i64::max_value());
-    assert_eq!(result, i64::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i64(i64::max_value()).as_str());
+    let result = buffer.format_i64(i64::MAX);
+    assert_eq!(result, i64::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_i64(i64::MAX).as_str());
 
-    let result = buffer.format_i64(i64::min_value());
-    assert_eq!(result, i64::min_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i64(i64::min_value()).as_str());
+    let result = buffer.format_i64(i64::MIN);
+    assert_eq!(result, i64::MIN.to_string());
+    assert_eq!(result, Buffer128::fmt_i64(i64::MIN).as_str());
 
-    let result = buffer.format_isize(isize::max_value());
-    assert_eq!(result, isize::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_isize(isize::max_value()).as_str());
+    let result = buffer.format_isize(isize::MAX);
+    assert_eq!(result, isize::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_isize(isize::MAX).as_str());
 
-    let result = buffer.format_isize(isize::min_value());
-    assert_eq!(result, isize::min_value().to_string());
-    assert_eq!(result, Buffer128::fmt_isize(isize::min_value()).as_str());
+    let result = buffer.format_isize(isize::MIN);
+    assert_eq!(result, isize::MIN.to_string());
+    assert_eq!(result, Buffer128::fmt_isize(isize::MIN).as_str());
 
-    let result = buffer.format_i128(i128::max_value());
-    assert_eq!(result, i128::max_value().to_string());
-    assert_eq!(result, Buffer128::fmt_i128(i128::max_value()).as_str());
+    let result = buffer.format_i128(i128::MAX);
+    assert_eq!(result, i128::MAX.to_string());
+    assert_eq!(result, Buffer128::fmt_i128(i128::MAX).as_str());
+}
+
+#[test]
+fn should_format_into_uninit_buffer() {
+    const U64_MAX_LEN: usize = {
+        let mut buffer = [MaybeUninit::uninit(); u64::TEXT_SIZE];
+        to_str::numeric::unsigned::u64(u64::MAX, &mut buffer).len()
+    };
+    assert_eq!(U64_MAX_LEN, u64::MAX.to_string().len());
+
+    let mut out = Vec::<u8>::with_capacity(64);
+    assert_eq!(u32::MAX.to_str_uninit(out.spare_capacity_mut()), u32::MAX.to_string());
+
+    let mut buffer = [MaybeUninit::uninit(); i128::TEXT_SIZE];
+    assert_eq!(to_str::numeric::signed::i128(i128::MIN, &mut buffer), i128::MIN.to_string());
+    assert_eq!(to_str::numeric::signed::isize(isize::MIN, &mut buffer), isize::MIN.to_string());
+    assert_eq!(to_str::numeric::unsigned::usize(usize::MAX, &mut buffer), usize::MAX.to_string());
+    assert_eq!(unsafe { to_str::numeric::unsigned::u128_unchecked(u128::MAX, &mut buffer) }, u128::MAX.to_string());
+    assert_eq!(unsafe { to_str::numeric::signed::i64_unchecked(i64::MIN, &mut buffer) }, i64::MIN.to_string());
+    assert_eq!(num::NonZeroI16::MIN.to_str_uninit(&mut buffer), i16::MIN.to_string());
+    assert_eq!(<&i64 as ToStr>::to_str_uninit(&&-5, &mut buffer), "-5");
+}
+
+#[test]
+#[should_panic]
+fn should_panic_on_insufficient_uninit_buffer() {
+    let mut buffer = [MaybeUninit::uninit(); u64::TEXT_SIZE - 1];
+    to_str::numeric::unsigned::u64(1, &mut buffer);
 }
 
 #[test]
 fn should_convert_u8() {
     let mut expected = String::with_capacity(u8::TEXT_SIZE);
     let mut buffer = [0u8; u8::TEXT_SIZE];
-    for num in [u8::min_value(), 10, 100, 150, u8::max_value()] {
+    for num in [u8::MIN, 10, 100, 150, u8::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -100,7 +129,7 @@ fn should_convert_u8() {
 fn should_convert_u16() {
     let mut expected = String::with_capacity(u16::TEXT_SIZE);
     let mut buffer = [0u8; u16::TEXT_SIZE];
-    for num in [u16::min_value(), 10, 105, 10_500, 50_123, u16::max_value()] {
+    for num in [u16::MIN, 10, 105, 10_500, 50_123, u16::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -116,7 +145,7 @@ fn should_convert_u16() {
 fn should_convert_u32() {
     let mut expected = String::with_capacity(u32::TEXT_SIZE);
     let mut buffer = [0u8; u32::TEXT_SIZE];
-    for num in [u32::min_value(), 10, 105, 10_500, 50_123, 250_987, u32::max_value()] {
+    for num in [u32::MIN, 10, 105, 10_500, 50_123, 250_987, u32::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -132,7 +161,7 @@ fn should_convert_u32() {
 fn should_convert_u128() {
     let mut expected = String::with_capacity(u128::TEXT_SIZE);
     let mut buffer = [0u8; u128::TEXT_SIZE];
-    let mut num = u128::max_value();
+    let mut num = u128::MAX;
     loop {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
@@ -146,7 +175,7 @@ fn should_convert_u128() {
         }
 
         expected.clear();
-        num /= u8::max_value() as u128;
+        num /= u8::MAX as u128;
     }
 }
 
@@ -176,7 +205,7 @@ fn should_convert_u128_without_missing_leading_zeros() {
 fn should_convert_i8() {
     let mut expected = String::with_capacity(i8::TEXT_SIZE);
     let mut buffer = [0u8; i8::TEXT_SIZE];
-    for num in [i8::min_value(), -11, 0, 11, i8::max_value()] {
+    for num in [i8::MIN, -11, 0, 11, i8::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -192,7 +221,7 @@ fn should_convert_i8() {
 fn should_convert_i16() {
     let mut expected = String::with_capacity(i16::TEXT_SIZE);
     let mut buffer = [0u8; i16::TEXT_SIZE];
-    for num in [i16::min_value(), -5_100, -11, 0, 11, 5_100, i16::max_value()] {
+    for num in [i16::MIN, -5_100, -11, 0, 11, 5_100, i16::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -208,7 +237,7 @@ fn should_convert_i16() {
 fn should_convert_i32() {
     let mut expected = String::with_capacity(i32::TEXT_SIZE);
     let mut buffer = [0u8; i32::TEXT_SIZE];
-    for num in [i32::min_value(), -250_765, -15_100, -11, 0, 11, 15_100, 250_765, i32::max_value()] {
+    for num in [i32::MIN, -250_765, -15_100, -11, 0, 11, 15_100, 250_765, i32::MAX] {
         let _ = write!(&mut expected, "{}", num);
         assert_eq!(num.to_str(&mut buffer), expected);
 
@@ -224,7 +253,7 @@ fn should_convert_i32() {
 fn should_convert_i128() {
     let mut expected = String::with_capacity(i128::TEXT_SIZE);
     let mut buffer = [0u8; i128::TEXT_SIZE];
-    let mut num = i128::max_value();
+    let mut num = i128::MAX;
 
     loop {
         let _ = write!(&mut expected, "{}", num);
@@ -240,10 +269,10 @@ fn should_convert_i128() {
             break;
         }
 
-        num /= u8::max_value() as i128;
+        num /= u8::MAX as i128;
     }
 
-    num = i128::min_value();
+    num = i128::MIN;
 
     loop {
         let _ = write!(&mut expected, "{}", num);
@@ -253,7 +282,7 @@ fn should_convert_i128() {
             break;
         }
 
-        num /= u8::max_value() as i128;
+        num /= u8::MAX as i128;
         expected.clear()
     }
 }
@@ -262,7 +291,7 @@ fn should_convert_i128() {
 fn should_convert_ptr() {
     let mut expected = String::with_capacity(<*const u8>::TEXT_SIZE);
     let mut buffer = [0u8; <*const u8>::TEXT_SIZE];
-    let mut num = usize::max_value();
+    let mut num = usize::MAX;
 
     loop {
         let ptr = num as *const u8;
@@ -273,7 +302,146 @@ fn should_convert_ptr() {
             break;
         }
 
-        num /= u8::max_value() as usize;
+        num /= u8::MAX as usize;
         expected.clear()
     }
 }
+
+#[test]
+fn should_write_to_front() {
+    fn assert_front<T: ToStr + core::fmt::Display>(num: T) {
+        let mut buffer = [0u8; 64];
+        let len = num.to_str_front(&mut buffer);
+        assert_eq!(core::str::from_utf8(&buffer[..len]).unwrap(), num.to_string());
+
+        let mut exact = vec![0u8; len];
+        assert_eq!(num.to_str_front(&mut exact), len);
+        assert_eq!(core::str::from_utf8(&exact).unwrap(), num.to_string());
+    }
+
+    let mut num = u128::MAX;
+    loop {
+        assert_front(num);
+        assert_front(num as u64);
+        assert_front(num as u32);
+        assert_front(num as u16);
+        assert_front(num as u8);
+        assert_front(num as usize);
+        assert_front(num as i128);
+        assert_front(num as i64);
+        assert_front(num as i32);
+        assert_front(num as i16);
+        assert_front(num as i8);
+        assert_front(num as isize);
+        assert_front(num as i128 / -3);
+        assert_front(num as i64 / -3);
+        assert_front(num as i8 / -3);
+
+        if num == 0 {
+            break;
+        }
+        num /= 10;
+    }
+
+    assert_front(i128::MIN);
+    assert_front(i64::MIN);
+    assert_front(isize::MIN);
+    assert_front(i8::MIN);
+    assert_front(num::NonZeroU32::MAX);
+    assert_front::<&num::NonZeroU32>(&num::NonZeroU32::MAX);
+
+    let ptr = usize::MAX as *const u8;
+    let mut buffer = [0u8; 32];
+    let len = ptr.to_str_front(&mut buffer);
+    assert_eq!(core::str::from_utf8(&buffer[..len]).unwrap(), format!("{:p}", ptr));
+}
+
+#[test]
+fn should_compute_decimal_len() {
+    use to_str::numeric::decimal_len;
+
+    const U64_MAX_LEN: usize = decimal_len::u64(u64::MAX);
+    assert_eq!(U64_MAX_LEN, u64::TEXT_SIZE);
+    assert_eq!(decimal_len::u128(u128::MAX), u128::TEXT_SIZE);
+    assert_eq!(decimal_len::i128(i128::MIN), i128::TEXT_SIZE);
+    assert_eq!(decimal_len::i8(i8::MIN), i8::TEXT_SIZE);
+    assert_eq!(decimal_len::isize(isize::MIN), isize::MIN.to_string().len());
+
+    let mut pow10 = 1u128;
+    loop {
+        for num in [pow10 - 1, pow10, pow10 + 1] {
+            assert_eq!(decimal_len::u128(num), num.to_string().len());
+            assert_eq!(num.text_len(), num.to_string().len());
+            assert_eq!((num as u64).text_len(), (num as u64).to_string().len());
+            assert_eq!((num as u32).text_len(), (num as u32).to_string().len());
+            assert_eq!((num as u16).text_len(), (num as u16).to_string().len());
+            assert_eq!((num as u8).text_len(), (num as u8).to_string().len());
+            assert_eq!((num as usize).text_len(), (num as usize).to_string().len());
+            assert_eq!((num as i64).text_len(), (num as i64).to_string().len());
+            assert_eq!((num as i64).wrapping_neg().text_len(), (num as i64).wrapping_neg().to_string().len());
+            assert_eq!((num as i128).wrapping_neg().text_len(), (num as i128).wrapping_neg().to_string().len());
+            assert_eq!((num as i16).wrapping_neg().text_len(), (num as i16).wrapping_neg().to_string().len());
+        }
+
+        pow10 = match pow10.checked_mul(10) {
+            Some(pow10) => pow10,
+            None => break,
+        };
+    }
+
+    let mut pow2 = 1u128;
+    while pow2 != 0 {
+        for num in [pow2 - 1, pow2, pow2 + 1] {
+            assert_eq!(decimal_len::u128(num), num.to_string().len());
+            assert_eq!(decimal_len::u64(num as u64), (num as u64).to_string().len());
+        }
+        pow2 <<= 1;
+    }
+}
+
+#[test]
+fn should_compute_text_len() {
+    struct Text(&'static str);
+
+    unsafe impl ToStr for Text {
+        const TEXT_SIZE: usize = 16;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let offset = buffer.len() - self.0.len();
+            buffer[offset..].copy_from_slice(self.0.as_bytes());
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    assert_eq!(Text("").text_len(), 0);
+    assert_eq!(Text("text").text_len(), 4);
+    assert_eq!(<&Text as ToStr>::text_len(&&Text("text")), 4);
+
+    for num in [0usize, 1, 15, 16, 255, 256, usize::MAX] {
+        let ptr = num as *const u8;
+        assert_eq!(ptr.text_len(), format!("{:p}", ptr).len());
+        assert_eq!((ptr as *mut u8).text_len(), format!("{:p}", ptr).len());
+    }
+    assert_eq!(num::NonZeroI64::MIN.text_len(), i64::MIN.to_string().len());
+}
+
+#[test]
+fn should_bound_text_len_of_big_text() {
+    struct Big(&'static str);
+
+    unsafe impl ToStr for Big {
+        const TEXT_SIZE: usize = 300;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            assert!(buffer.len() >= Self::TEXT_SIZE);
+            let offset = buffer.len() - self.0.len();
+            buffer[offset..].copy_from_slice(self.0.as_bytes());
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    //Without override text_len() is upper bound, which try_to_str() uses as required size
+    assert_eq!(Big("text").text_len(), Big::TEXT_SIZE);
+    assert_eq!(Big("text").try_to_str(&mut [0u8; 4]), Err(to_str::Error::BufferTooSmall { required: 300, available: 4 }));
+    assert_eq!(Big("text").try_to_str(&mut [0u8; 300]), Ok("text"));
+}
@@ -0,0 +1,58 @@
+use to_str::{Buffer, Error, ToStr};
+use to_str::option::{Dash, Empty, OptionOr, Placeholder};
+use to_str::testing::assert_contract;
+
+#[test]
+fn should_format_option() {
+    assert_eq!(<Option<u8>>::TEXT_SIZE, 4);
+    assert_eq!(<Option<u64>>::TEXT_SIZE, 20);
+
+    let mut buffer = [0u8; 32];
+    assert_eq!(Some(u64::MAX).to_str(&mut buffer), u64::MAX.to_string());
+    assert_eq!(None::<u64>.to_str(&mut buffer), "None");
+    assert_eq!(None::<u8>.text_len(), 4);
+    assert_eq!(None::<u8>.try_to_str(&mut buffer[..3]), Err(Error::BufferTooSmall { required: 4, available: 3 }));
+
+    assert_contract(&Some(i8::MIN));
+    assert_contract(&None::<i8>);
+    assert_contract(&Some(Some(1u8)));
+}
+
+#[test]
+fn should_format_option_with_placeholder() {
+    struct Null;
+
+    impl Placeholder for Null {
+        const TEXT: &'static str = "null";
+    }
+
+    assert_eq!(<OptionOr<u8, Null>>::TEXT_SIZE, 4);
+    assert_eq!(<OptionOr<u8, Empty>>::TEXT_SIZE, 3);
+
+    assert_eq!(Buffer::<4>::fmt(OptionOr::<u8, Null>::new(None)).as_str(), "null");
+    assert_eq!(Buffer::<4>::fmt(OptionOr::<u8, Null>::new(Some(1))).as_str(), "1");
+    assert_eq!(Buffer::<4>::fmt(OptionOr::<u8, Dash>::new(None)).as_str(), "-");
+    assert_eq!(Buffer::<4>::fmt(OptionOr::<u8, Empty>::new(None)).as_str(), "");
+
+    assert_contract(&OptionOr::<u8, Null>::new(None));
+    assert_contract(&OptionOr::<u8, Empty>::new(None));
+    assert_contract(&OptionOr::<i128, Dash>::new(Some(i128::MIN)));
+}
+
+#[test]
+fn should_format_result() {
+    assert_eq!(<Result<u8, i64>>::TEXT_SIZE, 26);
+    assert_eq!(<Result<u64, u8>>::TEXT_SIZE, 24);
+
+    let mut buffer = [0u8; 32];
+    assert_eq!(Ok::<u8, i64>(5).to_str(&mut buffer), "Ok(5)");
+    assert_eq!(Err::<u8, i64>(-5).to_str(&mut buffer), "Err(-5)");
+    assert_eq!(Err::<u8, i64>(-5).text_len(), 7);
+    assert_eq!(Ok::<u8, u8>(1).to_str(&mut buffer), format!("{:?}", Ok::<u8, u8>(1)));
+    assert_eq!(Err::<u8, u8>(1).to_str(&mut buffer), format!("{:?}", Err::<u8, u8>(1)));
+    assert_eq!(Ok::<u8, i64>(5).try_to_str(&mut buffer[..4]), Err(Error::BufferTooSmall { required: 5, available: 4 }));
+
+    assert_contract(&Ok::<u8, i64>(u8::MAX));
+    assert_contract(&Err::<u8, i64>(i64::MIN));
+    assert_contract(&Ok::<Option<u8>, u8>(None));
+}
@@ -0,0 +1,93 @@
+use core::cell::Cell;
+use core::cmp::{Ordering, Reverse};
+use core::num::{FpCategory, Saturating, Wrapping};
+use core::sync::atomic::{self, AtomicBool, AtomicI8, AtomicI64, AtomicU16, AtomicU32, AtomicUsize};
+
+use to_str::{Buffer, Error, ToStr};
+use to_str::testing::assert_contract;
+
+#[test]
+fn should_format_bool() {
+    assert_eq!(bool::TEXT_SIZE, 5);
+
+    let mut buffer = Buffer::<{ bool::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(true), "true");
+    assert_eq!(buffer.format(false), "false");
+    assert_eq!(true.try_to_str(&mut [0u8; 3]), Err(Error::BufferTooSmall { required: 4, available: 3 }));
+
+    assert_contract(&true);
+    assert_contract(&false);
+}
+
+#[test]
+fn should_format_char() {
+    assert_eq!(char::TEXT_SIZE, 4);
+
+    let mut buffer = [0u8; 4];
+    for ch in ['a', 'ß', '€', '😀', '\0', char::MAX] {
+        assert_eq!(ch.to_str(&mut buffer), ch.encode_utf8(&mut [0u8; 4]));
+        assert_eq!(ch.text_len(), ch.len_utf8());
+        assert_contract(&ch);
+    }
+}
+
+#[test]
+fn should_format_ordering_and_category() {
+    assert_eq!(Ordering::TEXT_SIZE, 7);
+    assert_eq!(FpCategory::TEXT_SIZE, 9);
+
+    let mut buffer = [0u8; 9];
+    for ordering in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+        assert_eq!(ordering.to_str(&mut buffer), format!("{ordering:?}"));
+        assert_contract(&ordering);
+    }
+
+    for category in [FpCategory::Nan, FpCategory::Infinite, FpCategory::Zero, FpCategory::Subnormal, FpCategory::Normal] {
+        assert_eq!(category.to_str(&mut buffer), format!("{category:?}"));
+        assert_contract(&category);
+    }
+}
+
+#[test]
+fn should_format_wrappers() {
+    assert_eq!(<Wrapping<i64>>::TEXT_SIZE, i64::TEXT_SIZE);
+    assert_eq!(<Saturating<u8>>::TEXT_SIZE, u8::TEXT_SIZE);
+    assert_eq!(<Reverse<u16>>::TEXT_SIZE, u16::TEXT_SIZE);
+    assert_eq!(<Cell<i32>>::TEXT_SIZE, i32::TEXT_SIZE);
+
+    let mut buffer = [0u8; i64::TEXT_SIZE];
+    assert_eq!(Wrapping(i64::MIN).to_str(&mut buffer), i64::MIN.to_string());
+    assert_eq!(Saturating(255u8).to_str(&mut buffer), "255");
+    assert_eq!(Reverse(-1i8).to_str(&mut buffer), "-1");
+    assert_eq!(Cell::new(42i32).to_str(&mut buffer), "42");
+    assert_eq!(Reverse(true).to_str(&mut buffer), "true");
+
+    assert_contract(&Wrapping(i64::MIN));
+    assert_contract(&Saturating(0u128));
+    assert_contract(&Reverse(Ordering::Less));
+    assert_contract(&Cell::new('€'));
+}
+
+#[test]
+fn should_format_atomics() {
+    assert_eq!(AtomicBool::TEXT_SIZE, bool::TEXT_SIZE);
+    assert_eq!(AtomicI8::TEXT_SIZE, i8::TEXT_SIZE);
+    assert_eq!(AtomicU16::TEXT_SIZE, u16::TEXT_SIZE);
+    assert_eq!(AtomicU32::TEXT_SIZE, u32::TEXT_SIZE);
+    assert_eq!(AtomicI64::TEXT_SIZE, i64::TEXT_SIZE);
+    assert_eq!(AtomicUsize::TEXT_SIZE, usize::TEXT_SIZE);
+
+    let value = AtomicI64::new(i64::MIN);
+    let mut buffer = [0u8; i64::TEXT_SIZE];
+    assert_eq!(value.to_str(&mut buffer), i64::MIN.to_string());
+    value.store(5, atomic::Ordering::Release);
+    assert_eq!(value.to_str(&mut buffer), "5");
+    assert_eq!(value.text_len(), 1);
+
+    assert_contract(&AtomicBool::new(true));
+    assert_contract(&AtomicI8::new(i8::MIN));
+    assert_contract(&AtomicU16::new(u16::MAX));
+    assert_contract(&AtomicU32::new(0));
+    assert_contract(&value);
+    assert_contract(&AtomicUsize::new(usize::MAX));
+}
@@ -0,0 +1,27 @@
+use to_str::{Buffer, ToStr};
+use to_str::testing::assert_contract;
+
+#[test]
+fn should_format_ranges() {
+    assert_eq!(<core::ops::Range<u8>>::TEXT_SIZE, 8);
+    assert_eq!(<core::ops::RangeInclusive<i8>>::TEXT_SIZE, 11);
+    assert_eq!(<core::ops::RangeFrom<u8>>::TEXT_SIZE, 5);
+    assert_eq!(<core::ops::RangeTo<u8>>::TEXT_SIZE, 5);
+    assert_eq!(<core::ops::RangeToInclusive<u8>>::TEXT_SIZE, 6);
+    assert_eq!(<core::ops::RangeFull>::TEXT_SIZE, 2);
+
+    assert_eq!(Buffer::<8>::fmt(3u8..7).as_str(), format!("{:?}", 3..7));
+    assert_eq!(Buffer::<16>::fmt(-128i8..=127).as_str(), format!("{:?}", -128..=127));
+    assert_eq!(Buffer::<8>::fmt(3u8..).as_str(), format!("{:?}", 3..));
+    assert_eq!(Buffer::<8>::fmt(..7u8).as_str(), format!("{:?}", ..7));
+    assert_eq!(Buffer::<8>::fmt(..=7u8).as_str(), format!("{:?}", ..=7));
+    assert_eq!(Buffer::<8>::fmt(..).as_str(), "..");
+
+    assert_contract(&(0..u64::MAX));
+    assert_contract(&(i128::MIN..=i128::MAX));
+    assert_contract(&(1u16..));
+    assert_contract(&(..1u16));
+    assert_contract(&(..=1u16));
+    assert_contract(&(..));
+    assert_contract(&(Some(1u8)..None));
+}
@@ -0,0 +1,101 @@
+use to_str::{Buffer, Case, Error, ToStr};
+use to_str::roman::{Roman, RomanExtended};
+use to_str::testing::assert_contract;
+
+//Reference conversion via subtractive pairs
+fn reference(mut num: u32) -> String {
+    const PAIRS: [(u32, &str); 23] = [
+        (1000000, "CCCCIↃↃↃↃ"), (900000, "ↈCCCCIↃↃↃↃ"), (500000, "IↃↃↃↃ"), (400000, "ↈIↃↃↃↃ"),
+        (100000, "ↈ"), (90000, "ↂↈ"), (50000, "ↇ"), (40000, "ↂↇ"), (10000, "ↂ"), (9000, "Mↂ"), (5000, "ↁ"), (4000, "Mↁ"),
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"),
+    ];
+    let mut text = String::new();
+    for (value, symbols) in PAIRS {
+        while num >= value {
+            text.push_str(symbols);
+            num -= value;
+        }
+    }
+    let ones = ["", "I", "II", "III", "IV"];
+    text.push_str(ones[num as usize]);
+    text
+}
+
+#[test]
+fn should_format_roman() {
+    assert_eq!(Roman::<u16>::TEXT_SIZE, 15);
+    assert_eq!(Roman::<u16>::MAX, 3999);
+
+    let mut buffer = Buffer::<{ Roman::<u16>::TEXT_SIZE }>::new();
+    for (num, expected) in [(1, "I"), (4, "IV"), (9, "IX"), (14, "XIV"), (40, "XL"), (90, "XC"), (400, "CD"), (900, "CM"), (1994, "MCMXCIV"), (2024, "MMXXIV"), (3888, "MMMDCCCLXXXVIII"), (3999, "MMMCMXCIX")] {
+        let roman = Roman::new(num as u16).unwrap();
+        assert_eq!(roman.value(), num as u16);
+        assert_eq!(buffer.format(roman), expected);
+        assert_eq!(buffer.format(roman.with_case(Case::Lower)), expected.to_ascii_lowercase());
+    }
+
+    let mut max_len = 0;
+    for num in 1..=3999u32 {
+        let roman = Roman::new(num).unwrap();
+        let expected = reference(num);
+        assert_eq!(buffer.format(roman), expected);
+        assert_eq!(roman.text_len(), expected.len());
+        max_len = max_len.max(expected.len());
+        assert_contract(&roman);
+        assert_contract(&roman.with_case(Case::Lower));
+    }
+    assert_eq!(max_len, Roman::<u32>::TEXT_SIZE);
+
+    assert_eq!(Roman::new(1994u32).unwrap().try_to_str(&mut [0u8; 6]), Err(Error::BufferTooSmall { required: 7, available: 6 }));
+}
+
+#[test]
+fn should_reject_out_of_range() {
+    assert!(Roman::new(0u8).is_none());
+    assert!(Roman::new(-1i32).is_none());
+    assert!(Roman::new(4000u16).is_none());
+    assert!(Roman::new(u64::MAX).is_none());
+    assert!(Roman::new(i128::MIN).is_none());
+    assert!(Roman::new(255u8).is_some());
+    assert!(Roman::new(3999i64).is_some());
+
+    assert!(RomanExtended::new(0u32).is_none());
+    assert!(RomanExtended::new(4_000_000u32).is_none());
+    assert!(RomanExtended::new(-1i64).is_none());
+    assert!(RomanExtended::new(3_999_999u32).is_some());
+    assert!(RomanExtended::new(400_000u32).is_some());
+}
+
+#[test]
+fn should_format_roman_extended() {
+    assert_eq!(RomanExtended::<u32>::TEXT_SIZE, 103);
+    assert_eq!(RomanExtended::<u32>::MAX, 3_999_999);
+
+    let mut buffer = Buffer::<{ RomanExtended::<u32>::TEXT_SIZE }>::new();
+    for (num, expected) in [
+        (3999, "MMMCMXCIX"), (4000, "Mↁ"), (5000, "ↁ"), (9000, "Mↂ"), (16000, "ↂↁM"), (40000, "ↂↇ"), (90000, "ↂↈ"),
+        (399_999, "ↈↈↈↂↈMↂCMXCIX"), (400_000, "ↈIↃↃↃↃ"), (500_000, "IↃↃↃↃ"), (900_000, "ↈCCCCIↃↃↃↃ"), (1_000_000, "CCCCIↃↃↃↃ"),
+        (3_999_999, "CCCCIↃↃↃↃCCCCIↃↃↃↃCCCCIↃↃↃↃↈCCCCIↃↃↃↃↂↈMↂCMXCIX"),
+    ] {
+        let roman = RomanExtended::new(num).unwrap();
+        assert_eq!(buffer.format(roman), expected);
+        assert_eq!(buffer.format(roman.with_case(Case::Lower)), expected.to_lowercase());
+        assert_contract(&roman);
+    }
+    assert_eq!(buffer.format(RomanExtended::new(4444u32).unwrap().with_case(Case::Lower)), "mↁcdxliv");
+    assert_eq!(buffer.format(RomanExtended::new(1_500_000u32).unwrap().with_case(Case::Lower)), "cccciↄↄↄↄiↄↄↄↄ");
+
+    let mut max_len = 0;
+    for num in 1..=3_999_999u32 {
+        let roman = RomanExtended::new(num).unwrap();
+        let text = buffer.format(roman);
+        assert_eq!(text, reference(num));
+        assert_eq!(roman.text_len(), text.len());
+        max_len = max_len.max(text.len());
+        if num % 997 == 0 {
+            assert_contract(&roman.with_case(Case::Lower));
+        }
+    }
+    assert_eq!(max_len, RomanExtended::<u32>::TEXT_SIZE);
+    assert_eq!(RomanExtended::new(3_888_888u32).unwrap().text_len(), RomanExtended::<u32>::TEXT_SIZE);
+}
@@ -0,0 +1,81 @@
+use to_str::{Buffer, Error, ToStr};
+use to_str::seq::{Brackets, Joined, Spaced, Style};
+use to_str::testing::assert_contract;
+
+#[test]
+fn should_format_tuples_like_debug() {
+    let mut buffer = [0u8; 512];
+
+    let value = (u8::MAX, i8::MIN);
+    assert_eq!(<(u8, i8)>::TEXT_SIZE, 3 + 4 + 4);
+    assert_eq!(value.to_str(&mut buffer), format!("{:?}", value));
+    assert_contract(&value);
+
+    let value = (1u8, -2i16, 3u32, -4i64, 5u128, -6isize, 7usize, -8i8, 9u16, -10i32, 11u64, i128::MIN);
+    assert_eq!(value.to_str(&mut buffer), format!("{:?}", value));
+    assert_eq!(value.text_len(), format!("{:?}", value).len());
+    assert_contract(&value);
+
+    let value = ((1u8, 2u8), [3u8, 4], (5u8,));
+    assert_eq!(value.to_str(&mut buffer), "((1, 2), [3, 4], (5,))");
+    assert_eq!(value.to_str(&mut buffer), format!("{:?}", value));
+    assert_contract(&value);
+
+    let value = (-1i8,);
+    assert_eq!(<(i8,)>::TEXT_SIZE, 4 + 3);
+    assert_eq!(value.to_str(&mut buffer), "(-1,)");
+    assert_eq!(value.text_len(), 5);
+    assert_eq!(Buffer::<{ <(i8,)>::TEXT_SIZE }>::fmt((i8::MIN,)).as_str(), "(-128,)");
+    assert_eq!(Buffer::<4>::fmt(Joined::<_, Spaced>::new((5u8,))).as_str(), "5");
+    assert_contract(&value);
+}
+
+#[test]
+fn should_format_arrays_like_debug() {
+    let mut buffer = [0u8; 512];
+
+    let value = [u64::MAX, 0, 10];
+    assert_eq!(<[u64; 3]>::TEXT_SIZE, 2 + 20 * 3 + 2 * 2);
+    assert_eq!(value.to_str(&mut buffer), format!("{:?}", value));
+    assert_contract(&value);
+
+    let value: [u8; 0] = [];
+    assert_eq!(<[u8; 0]>::TEXT_SIZE, 2);
+    assert_eq!(value.to_str(&mut buffer), "[]");
+    assert_contract(&value);
+
+    let value = [-1i8];
+    assert_eq!(value.to_str(&mut buffer), "[-1]");
+    assert_contract(&value);
+}
+
+#[test]
+fn should_format_in_custom_style() {
+    struct Semicolon;
+
+    impl Style for Semicolon {
+        const OPEN: &'static str = "<";
+        const SEPARATOR: &'static str = ";";
+        const CLOSE: &'static str = ">";
+    }
+
+    let value = Joined::<_, Spaced>::new([1u8, 20, 255]);
+    assert_eq!(Buffer::<{<Joined<[u8; 3], Spaced>>::TEXT_SIZE}>::fmt(value).as_str(), "1 20 255");
+    assert_contract(&value);
+
+    let value = Joined::<_, Semicolon>::new((1u8, -1i8));
+    assert_eq!(<Joined<(u8, i8), Semicolon>>::TEXT_SIZE, 1 + 3 + 1 + 4 + 1);
+    assert_eq!(Buffer::<16>::fmt(value).as_str(), "<1;-1>");
+    assert_contract(&value);
+
+    let value = Joined::<_, Brackets>::new((1u8, 2u8));
+    assert_eq!(Buffer::<16>::fmt(value).as_str(), "[1, 2]");
+}
+
+#[test]
+fn should_report_insufficient_buffer() {
+    let mut buffer = [0u8; 6];
+    assert_eq!((1u8, 2u8).try_to_str(&mut buffer), Ok("(1, 2)"));
+    assert_eq!((10u8, 2u8).try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 7, available: 6 }));
+    assert_eq!([100u8; 3].try_to_str(&mut buffer), Err(Error::BufferTooSmall { required: 15, available: 6 }));
+}
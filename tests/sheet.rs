@@ -0,0 +1,77 @@
+use core::num::NonZeroU32;
+
+use to_str::{Buffer, Error, ParseError, ToStr};
+use to_str::sheet::{CellRef, Column};
+use to_str::testing::assert_contract;
+
+fn column(number: u32) -> Column {
+    Column::from_number(number).unwrap()
+}
+
+fn cell(column_number: u32, row: u32) -> CellRef {
+    CellRef::new(column(column_number), NonZeroU32::new(row).unwrap())
+}
+
+#[test]
+fn should_format_column() {
+    assert_eq!(Column::TEXT_SIZE, 7);
+    assert!(Column::from_number(0).is_none());
+
+    let mut buffer = Buffer::<{ Column::TEXT_SIZE }>::new();
+    for (number, expected) in [(1, "A"), (2, "B"), (26, "Z"), (27, "AA"), (52, "AZ"), (53, "BA"), (702, "ZZ"), (703, "AAA"), (16384, "XFD"), (u32::MAX, "MWLQKWU")] {
+        let column = column(number);
+        assert_eq!(buffer.format(column), expected);
+        assert_eq!(column.text_len(), expected.len());
+        assert_eq!(Column::parse_str(expected), Ok(column));
+        assert_eq!(expected.to_ascii_lowercase().parse::<Column>(), Ok(column));
+        assert_contract(&column);
+    }
+    assert_eq!(Column::FIRST, column(1));
+    assert_eq!(Column::MAX.get(), u32::MAX);
+
+    for number in 1..=20_000 {
+        let column = column(number);
+        let text = buffer.format(column);
+        assert_eq!(Column::parse_str(text), Ok(column));
+    }
+
+    assert_eq!(column(703).try_to_str(&mut [0u8; 2]), Err(Error::BufferTooSmall { required: 3, available: 2 }));
+}
+
+#[test]
+fn should_parse_column_errors() {
+    assert_eq!(Column::parse_str(""), Err(ParseError::InvalidLength { len: 0 }));
+    assert_eq!(Column::parse_str("A1"), Err(ParseError::InvalidCharacter { index: 1 }));
+    assert_eq!(Column::parse_str("Ä"), Err(ParseError::InvalidCharacter { index: 0 }));
+    assert_eq!(Column::parse_str("MWLQKWV"), Err(ParseError::Overflow));
+    assert_eq!(Column::parse_str("AAAAAAAA"), Err(ParseError::Overflow));
+}
+
+#[test]
+fn should_format_cell_ref() {
+    assert_eq!(CellRef::TEXT_SIZE, 17);
+
+    let mut buffer = Buffer::<{ CellRef::TEXT_SIZE }>::new();
+    for (cell, expected) in [(cell(1, 1), "A1"), (cell(2, 12), "B12"), (cell(16384, 1048576), "XFD1048576"), (cell(u32::MAX, u32::MAX), "MWLQKWU4294967295")] {
+        assert_eq!(buffer.format(cell), expected);
+        assert_eq!(cell.text_len(), expected.len());
+        assert_eq!(CellRef::parse_str(expected), Ok(cell));
+        assert_eq!(expected.to_ascii_lowercase().parse::<CellRef>(), Ok(cell));
+        assert_contract(&cell);
+    }
+
+    assert_eq!(cell(2, 12).try_to_str(&mut [0u8; 2]), Err(Error::BufferTooSmall { required: 3, available: 2 }));
+}
+
+#[test]
+fn should_parse_cell_ref_errors() {
+    assert_eq!(CellRef::parse_str(""), Err(ParseError::InvalidLength { len: 0 }));
+    assert_eq!(CellRef::parse_str("AB"), Err(ParseError::InvalidLength { len: 2 }));
+    assert_eq!(CellRef::parse_str("12"), Err(ParseError::InvalidCharacter { index: 0 }));
+    assert_eq!(CellRef::parse_str("B0"), Err(ParseError::InvalidCharacter { index: 1 }));
+    assert_eq!(CellRef::parse_str("B012"), Err(ParseError::InvalidCharacter { index: 1 }));
+    assert_eq!(CellRef::parse_str("B1C"), Err(ParseError::InvalidCharacter { index: 2 }));
+    assert_eq!(CellRef::parse_str("$B$1"), Err(ParseError::InvalidCharacter { index: 0 }));
+    assert_eq!(CellRef::parse_str("B4294967296"), Err(ParseError::Overflow));
+    assert_eq!(CellRef::parse_str("AAAAAAAA1"), Err(ParseError::Overflow));
+}
@@ -0,0 +1,152 @@
+use to_str::{Buffer, Error, ParseError, ToStr};
+use to_str::sortable::{Alphabet, Base32Hex, Decimal, Hex, Key, Sortable};
+use to_str::testing::assert_contract;
+
+fn check_order<T: Key + KeyBits + Copy, A: Alphabet>(values: &[T]) {
+    let texts: Vec<String> = values.iter().map(|value| {
+        let mut buffer = [0u8; 64];
+        let sortable = Sortable::<T, A>::new(*value);
+        assert_contract(&sortable);
+        let text = sortable.to_str(&mut buffer).to_owned();
+        assert_eq!(text.len(), Sortable::<T, A>::WIDTH);
+        text
+    }).collect();
+
+    for (value, text) in values.iter().zip(texts.iter()) {
+        assert_eq!(Sortable::<T, A>::decode(text).unwrap().to_key_bits(), value.to_key_bits());
+    }
+    for pair in texts.windows(2) {
+        assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+    }
+}
+
+//Compares values by bits, so that NaN and signed zeros round trip exactly
+trait KeyBits {
+    fn to_key_bits(&self) -> u128;
+}
+
+macro_rules! impl_key_bits {
+    ($($t:ty => |$this:ident| $bits:expr;)+) => {
+        $(
+            impl KeyBits for $t {
+                fn to_key_bits(&self) -> u128 {
+                    let $this = *self;
+                    $bits
+                }
+            }
+        )+
+    };
+}
+
+impl_key_bits!(
+    u8 => |this| this as u128;
+    u16 => |this| this as u128;
+    u32 => |this| this as u128;
+    u64 => |this| this as u128;
+    u128 => |this| this;
+    usize => |this| this as u128;
+    i8 => |this| this as u8 as u128;
+    i16 => |this| this as u16 as u128;
+    i32 => |this| this as u32 as u128;
+    i64 => |this| this as u64 as u128;
+    i128 => |this| this as u128;
+    isize => |this| this as usize as u128;
+    f64 => |this| this.to_bits() as u128;
+);
+
+macro_rules! check_ints {
+    ($($unsigned:ty, $signed:ty;)+) => {
+        $(
+            let unsigned = [0, 1, 9, 10, 255 as $unsigned, <$unsigned>::MAX / 2, <$unsigned>::MAX - 1, <$unsigned>::MAX];
+            let signed = [<$signed>::MIN, <$signed>::MIN + 1, -10, -1, 0, 1, 10, <$signed>::MAX - 1, <$signed>::MAX];
+            check_order::<$unsigned, Decimal>(&unsigned);
+            check_order::<$unsigned, Hex>(&unsigned);
+            check_order::<$unsigned, Base32Hex>(&unsigned);
+            check_order::<$signed, Decimal>(&signed);
+            check_order::<$signed, Hex>(&signed);
+            check_order::<$signed, Base32Hex>(&signed);
+        )+
+    };
+}
+
+#[test]
+fn should_preserve_order_of_integers() {
+    check_ints!(
+        u16, i16;
+        u32, i32;
+        u64, i64;
+        u128, i128;
+        usize, isize;
+    );
+    check_order::<u8, Decimal>(&[0, 1, 9, 10, 99, 100, 254, 255]);
+    check_order::<i8, Hex>(&[i8::MIN, -1, 0, 1, i8::MAX]);
+    check_order::<i8, Base32Hex>(&[i8::MIN, -1, 0, 1, i8::MAX]);
+}
+
+#[test]
+fn should_preserve_order_of_floats() {
+    let values = [
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -1.5,
+        -1.0,
+        -f64::MIN_POSITIVE,
+        -0.0,
+        0.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        1.0,
+        1.5,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NAN,
+    ];
+    check_order::<f64, Decimal>(&values);
+    check_order::<f64, Hex>(&values);
+    check_order::<f64, Base32Hex>(&values);
+
+    assert_eq!(Sortable::<f64, Hex>::decode("7fffffffffffffff").map(f64::to_bits), Ok((-0.0f64).to_bits()));
+    assert!(Sortable::<f64, Hex>::decode(Buffer::<16>::fmt(Sortable::<_, Hex>::new(f64::NAN)).as_str()).unwrap().is_nan());
+}
+
+#[test]
+fn should_write_fixed_width() {
+    assert_eq!(<Sortable<u8>>::TEXT_SIZE, 3);
+    assert_eq!(<Sortable<i8>>::TEXT_SIZE, 3);
+    assert_eq!(<Sortable<u64>>::TEXT_SIZE, 20);
+    assert_eq!(<Sortable<i128>>::TEXT_SIZE, 39);
+    assert_eq!(<Sortable<f64>>::TEXT_SIZE, 20);
+    assert_eq!(<Sortable<u32, Hex>>::TEXT_SIZE, 8);
+    assert_eq!(<Sortable<u128, Hex>>::TEXT_SIZE, 32);
+    assert_eq!(<Sortable<u8, Base32Hex>>::TEXT_SIZE, 2);
+    assert_eq!(<Sortable<u64, Base32Hex>>::TEXT_SIZE, 13);
+    assert_eq!(<Sortable<i128, Base32Hex>>::TEXT_SIZE, 26);
+
+    let mut buffer = Buffer::<39>::new();
+    assert_eq!(buffer.format(Sortable::<_>::new(5u32)), "0000000005");
+    assert_eq!(buffer.format(Sortable::<_>::new(i32::MIN)), "0000000000");
+    assert_eq!(buffer.format(Sortable::<_>::new(-1i32)), "2147483647");
+    assert_eq!(buffer.format(Sortable::<_>::new(0i32)), "2147483648");
+    assert_eq!(buffer.format(Sortable::<_>::new(u128::MAX)), u128::MAX.to_string());
+    assert_eq!(buffer.format(Sortable::<_>::new(1u128 << 64)), format!("{:039}", 1u128 << 64));
+    assert_eq!(buffer.format(Sortable::<_, Hex>::new(0xabu16)), "00ab");
+    assert_eq!(buffer.format(Sortable::<_, Base32Hex>::new(u8::MAX)), "7V");
+    assert_eq!(buffer.format(Sortable::<_, Base32Hex>::new(0i8)), "40");
+
+    assert_eq!(Sortable::<u16, Decimal>::new(1).try_to_str(&mut [0u8; 4]), Err(Error::BufferTooSmall { required: 5, available: 4 }));
+}
+
+#[test]
+fn should_reject_invalid_text() {
+    assert_eq!(Sortable::<u8, Decimal>::decode("25"), Err(ParseError::InvalidLength { len: 2 }));
+    assert_eq!(Sortable::<u8, Decimal>::decode("256"), Err(ParseError::Overflow));
+    assert_eq!(Sortable::<u8, Decimal>::decode("2a5"), Err(ParseError::InvalidCharacter { index: 1 }));
+    assert_eq!(Sortable::<u128, Decimal>::decode("999999999999999999999999999999999999999"), Err(ParseError::Overflow));
+    assert_eq!(Sortable::<u16, Hex>::decode("00AB"), Err(ParseError::InvalidCharacter { index: 2 }));
+    assert_eq!(Sortable::<u8, Base32Hex>::decode("80"), Err(ParseError::Overflow));
+    assert_eq!(Sortable::<u8, Base32Hex>::decode("7W"), Err(ParseError::InvalidCharacter { index: 1 }));
+    assert_eq!(Sortable::<u128, Base32Hex>::decode("8000000000000000000000000"), Err(ParseError::InvalidLength { len: 25 }));
+    assert_eq!(Sortable::<u128, Base32Hex>::decode("80000000000000000000000000"), Err(ParseError::Overflow));
+    assert_eq!(Sortable::<u128, Base32Hex>::decode("7VVVVVVVVVVVVVVVVVVVVVVVVV"), Ok(u128::MAX));
+    assert_eq!(Sortable::<i8, Decimal>::decode("000"), Ok(i8::MIN));
+}
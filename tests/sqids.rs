@@ -0,0 +1,154 @@
+use to_str::{Buffer, Error, ParseError, ToStr};
+use to_str::sqids::{Sqids, SqidsId};
+use to_str::testing::assert_contract;
+
+fn encode<const K: usize>(sqids: &Sqids<'_>, numbers: [u64; K]) -> String {
+    let id = sqids.encode(numbers);
+    assert_contract(&id);
+
+    let mut buffer = [0u8; 512];
+    let text = id.to_str(&mut buffer).to_owned();
+    assert_eq!(id.text_len(), text.len());
+
+    let mut decoded = [0u64; 8];
+    assert_eq!(sqids.decode(&text, &mut decoded), Ok(&numbers[..]));
+    text
+}
+
+#[test]
+fn should_encode_with_default_alphabet() {
+    let sqids = Sqids::DEFAULT;
+    assert_eq!(encode(&sqids, [1, 2, 3]), "86Rf07");
+    assert_eq!(encode(&sqids, []), "");
+
+    let incremental = ["bM", "Uk", "gb", "Ef", "Vq", "uw", "OI", "AX", "p6", "nJ"];
+    for (num, expected) in incremental.iter().enumerate() {
+        assert_eq!(encode(&sqids, [num as u64]), *expected);
+    }
+
+    assert_eq!(encode(&sqids, [0, 0]), "SvIz");
+    assert_eq!(encode(&sqids, [0, 1]), "n3qa");
+
+    for numbers in [[0, u64::MAX], [u64::MAX, u64::MAX], [12345, 67890]] {
+        encode(&sqids, numbers);
+    }
+}
+
+#[test]
+fn should_encode_with_min_length() {
+    let sqids = Sqids::DEFAULT.with_min_length(62);
+    assert_eq!(encode(&sqids, [1, 2, 3]), "86Rf07xd4zBmiJXQG6otHEbew02c3PWsUOLZxADhCpKj7aVFv9I8RquYrNlSTM");
+
+    for min_length in [0, 1, 5, 10, 62, 100, u8::MAX] {
+        let sqids = Sqids::DEFAULT.with_min_length(min_length);
+        for numbers in [[0, 0, 0], [1, 2, 3], [u64::MAX, 0, u64::MAX]] {
+            assert!(encode(&sqids, numbers).len() >= min_length as usize);
+        }
+        assert!(encode(&sqids, [u64::MAX]).len() >= min_length as usize);
+    }
+}
+
+#[test]
+fn should_encode_with_custom_alphabet() {
+    let sqids = Sqids::new("0123456789abcdef").unwrap();
+    assert_eq!(encode(&sqids, [1, 2, 3]), "489158");
+
+    let sqids = Sqids::new("abc").unwrap();
+    encode(&sqids, [1, 2, 3, 4, 5]);
+    encode(&sqids, [u64::MAX]);
+
+    assert_eq!(Sqids::new("ab"), Err(ParseError::InvalidLength { len: 2 }));
+    assert_eq!(Sqids::new("abca"), Err(ParseError::InvalidCharacter { index: 3 }));
+    assert_eq!(Sqids::new("abcë"), Err(ParseError::InvalidCharacter { index: 3 }));
+}
+
+#[test]
+fn should_avoid_blocked_words() {
+    let blocklist = ["JSwXFaosAN", "OCjV9JK64o", "rBHf", "79SM", "7tE6"];
+    let sqids = Sqids::DEFAULT.with_blocklist(&blocklist);
+    assert_eq!(encode(&sqids, [1_000_000, 2_000_000]), "1aYeB7bRUt");
+
+    let mut decoded = [0u64; 2];
+    assert_eq!(sqids.decode("1aYeB7bRUt", &mut decoded), Ok(&[1_000_000, 2_000_000][..]));
+
+    //Unblocked ID is still decodable
+    assert_eq!(encode(&Sqids::DEFAULT, [1, 2, 3]), "86Rf07");
+    let sqids = Sqids::DEFAULT.with_blocklist(&["86Rf07"]);
+    assert_ne!(encode(&sqids, [1, 2, 3]), "86Rf07");
+    assert_eq!(sqids.decode("86Rf07", &mut [0u64; 3]), Ok(&[1, 2, 3][..]));
+
+    //Short words and words with characters outside of alphabet are ignored
+    let sqids = Sqids::DEFAULT.with_blocklist(&["bM", "b-M"]);
+    assert_eq!(encode(&sqids, [0]), "bM");
+    let sqids = Sqids::DEFAULT.with_blocklist(&["BM", "uk"]);
+    assert_eq!(encode(&sqids, [1]), "Uk");
+    let sqids = Sqids::DEFAULT.with_blocklist(&["86rf07"]);
+    assert_ne!(encode(&sqids, [1, 2, 3]), "86Rf07");
+}
+
+#[test]
+fn should_decode() {
+    let sqids = Sqids::DEFAULT;
+    let mut numbers = [0u64; 2];
+    assert_eq!(sqids.decode("", &mut numbers), Ok(&[][..]));
+    assert_eq!(sqids.decode("86Rf07", &mut numbers), Err(ParseError::Overflow));
+    assert_eq!(sqids.decode("86Rf-7", &mut numbers), Err(ParseError::InvalidCharacter { index: 4 }));
+    assert_eq!(sqids.decode("bM", &mut numbers), Ok(&[0][..]));
+
+    let max = encode(&sqids, [u64::MAX]);
+    let mut overflowed = max.clone();
+    overflowed.insert(1, max.as_bytes()[1] as char);
+    assert!(sqids.decode(&overflowed, &mut numbers).is_err());
+}
+
+#[test]
+fn should_write_into_buffer() {
+    assert_eq!(<SqidsId<'_, 0>>::TEXT_SIZE, 0);
+    assert_eq!(<SqidsId<'_, 1>>::TEXT_SIZE, 255);
+    assert_eq!(<SqidsId<'_, 4>>::TEXT_SIZE, 260);
+
+    let sqids = Sqids::DEFAULT;
+    let mut buffer = Buffer::<8>::new();
+    assert_eq!(buffer.try_format(sqids.encode([1, 2, 3])), Ok("86Rf07"));
+    assert_eq!(sqids.encode([1, 2, 3]).try_to_str(&mut [0u8; 5]), Err(Error::BufferTooSmall { required: 6, available: 5 }));
+
+    let mut buffer = Buffer::<{ <SqidsId<'_, 1>>::TEXT_SIZE }>::new();
+    assert_eq!(buffer.format(sqids.encode([0])), "bM");
+}
+
+//Every 4 letter word over `abc`, therefore any ID of 4 or more characters contains blocked word
+fn exhaustive_blocklist() -> Vec<String> {
+    let mut words = Vec::new();
+    for idx in 0..81 {
+        let word = (0..4).map(|pos| b"abc"[idx / 3usize.pow(pos) % 3] as char).collect();
+        words.push(word);
+    }
+    words
+}
+
+#[test]
+fn should_fail_when_blocklist_rejects_every_attempt() {
+    let words = exhaustive_blocklist();
+    let blocklist = words.iter().map(String::as_str).collect::<Vec<_>>();
+    let sqids = Sqids::new("abc").unwrap().with_min_length(10).with_blocklist(&blocklist);
+
+    let id = sqids.encode([1, 2]);
+    assert_eq!(id.text_len(), 10);
+    let mut buffer = [0u8; 512];
+    assert_eq!(id.try_to_str(&mut buffer), Err(Error::Unrepresentable));
+    assert_eq!(Buffer::<{ <SqidsId<'_, 2>>::TEXT_SIZE }>::new().try_format(id), Err(Error::Unrepresentable));
+
+    //Blocklist of other alphabet doesn't reject anything
+    let sqids = Sqids::new("xyz").unwrap().with_min_length(10).with_blocklist(&blocklist);
+    assert_eq!(encode(&sqids, [1, 2]).len(), 10);
+}
+
+#[test]
+#[should_panic(expected = "Value has no acceptable textual representation")]
+fn should_panic_in_to_str_when_blocklist_rejects_every_attempt() {
+    let words = exhaustive_blocklist();
+    let blocklist = words.iter().map(String::as_str).collect::<Vec<_>>();
+    let sqids = Sqids::new("abc").unwrap().with_min_length(10).with_blocklist(&blocklist);
+
+    sqids.encode([1, 2]).to_str(&mut [0u8; 512]);
+}
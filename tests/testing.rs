@@ -0,0 +1,97 @@
+use to_str::ToStr;
+use to_str::testing::{assert_contract, verify, Violation};
+
+use core::{num, ptr};
+
+#[test]
+fn should_satisfy_contract_for_numbers() {
+    let mut num = u128::MAX;
+    loop {
+        assert_contract(&num);
+        assert_contract(&(num as u64));
+        assert_contract(&(num as u32));
+        assert_contract(&(num as u16));
+        assert_contract(&(num as u8));
+        assert_contract(&(num as usize));
+        assert_contract(&(num as i128).wrapping_neg());
+        assert_contract(&(num as i64).wrapping_neg());
+        assert_contract(&(num as i32));
+        assert_contract(&(num as i16).wrapping_neg());
+        assert_contract(&(num as i8));
+        assert_contract(&(num as isize).wrapping_neg());
+
+        if num == 0 {
+            break;
+        }
+        num /= 7;
+    }
+
+    assert_contract(&num::NonZeroI128::MIN);
+    let mut non_zero = num::NonZeroUsize::MAX;
+    assert_contract(&&mut non_zero);
+    assert_contract(&(usize::MAX as *const u8));
+    assert_contract(&ptr::NonNull::<u8>::dangling());
+}
+
+#[test]
+fn should_detect_contract_violation() {
+    struct Front;
+
+    unsafe impl ToStr for Front {
+        const TEXT_SIZE: usize = 4;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            buffer[..4].copy_from_slice(b"text");
+            core::str::from_utf8(&buffer[..4]).unwrap()
+        }
+    }
+
+    struct Dirty;
+
+    unsafe impl ToStr for Dirty {
+        const TEXT_SIZE: usize = 4;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            buffer[0] = b'0';
+            let offset = buffer.len() - 2;
+            buffer[offset..].copy_from_slice(b"ok");
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    struct Long;
+
+    unsafe impl ToStr for Long {
+        const TEXT_SIZE: usize = 1;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let offset = buffer.len().saturating_sub(2);
+            buffer[offset..].fill(b'1');
+            core::str::from_utf8(&buffer[offset..]).unwrap()
+        }
+    }
+
+    assert_eq!(verify(&Front), Err(Violation::NotAtEnd));
+    assert_eq!(verify(&Dirty), Err(Violation::CanaryOverwritten { index: 0 }));
+    assert_eq!(verify(&Long), Err(Violation::TooLong { len: 2 }));
+}
+
+#[test]
+#[should_panic]
+fn should_panic_on_contract_violation() {
+    struct Poisoned;
+
+    unsafe impl ToStr for Poisoned {
+        const TEXT_SIZE: usize = 2;
+
+        fn to_str<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+            let offset = buffer.len() - 2;
+            //Pretends that unwritten byte is valid text
+            unsafe {
+                core::str::from_utf8_unchecked(&buffer[offset..])
+            }
+        }
+    }
+
+    assert_contract(&Poisoned);
+}